@@ -0,0 +1,141 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use common::{input::Source, Part};
+
+use days::Day;
+
+use crate::{run, timing};
+
+/// The outcome of running a day on one file of a directory.
+pub struct Row {
+    pub path: PathBuf,
+    pub answers: [Option<String>; 2],
+    /// What the solver pointed out about the file besides the answers, by part.
+    pub warnings: Vec<(Part, String)>,
+    pub time: Duration,
+    /// The first problem with this file, if there was one.
+    pub error: Option<String>,
+}
+
+impl Row {
+    pub fn cells(&self) -> Vec<String> {
+        let name = self.path.file_name().unwrap_or_default().to_string_lossy();
+        let [one, two] = self
+            .answers
+            .clone()
+            .map(|answer| answer.unwrap_or("-".into()));
+        let error = self.error.as_deref().and_then(|error| error.lines().next());
+
+        vec![
+            name.into_owned(),
+            one,
+            two,
+            timing::format(self.time),
+            error.unwrap_or_default().to_string(),
+        ]
+    }
+}
+
+/// Runs both parts of `day` on every file in `directory`, in the order of their names.
+///
+/// Files that cannot be read or parsed, or make a part panic, still get a row with the error.
+pub fn run(day: &Day, directory: &Path) -> io::Result<Vec<Row>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(directory)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<_>>()?;
+    paths.retain(|path| path.is_file() && !is_hidden(path));
+    paths.sort();
+
+    // Panics end up in the rows; `Day::try_solve` keeps them from cluttering the table.
+    let rows = paths
+        .into_iter()
+        .map(|path| {
+            let run = run::run(day, &Source::Path(path.clone()), &[Part::One, Part::Two]);
+
+            // Parsing is shared by both parts, so it only counts once.
+            let parse = run.parts.first().and_then(|part| part.phases.parse);
+            let solve = run.parts.iter().filter_map(|part| part.phases.solve);
+            let time = parse.into_iter().chain(solve).sum();
+
+            let error = run.parts.iter().find_map(|part| part.answer.clone().err());
+            let warnings = run
+                .parts
+                .iter()
+                .flat_map(|part| {
+                    part.warnings
+                        .iter()
+                        .map(|warning| (part.part, warning.clone()))
+                })
+                .collect();
+            let mut answers = [None, None];
+            for (answer, part) in answers.iter_mut().zip(run.parts) {
+                *answer = part.answer.ok();
+            }
+
+            Row {
+                path,
+                answers,
+                warnings,
+                time,
+                error,
+            }
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with('.'))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, process};
+
+    use common::input;
+
+    #[test]
+    fn running_a_directory() {
+        let directory = env::temp_dir().join(format!("aoc-batch-{}", process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        fs::copy(
+            input::day_directory(9).join("test_input"),
+            directory.join("example"),
+        )
+        .unwrap();
+        fs::write(directory.join("broken"), "0 3 6\n1 x 3\n").unwrap();
+        fs::write(directory.join(".hidden"), "").unwrap();
+
+        let rows = super::run(&days::find(9).unwrap(), &directory).unwrap();
+
+        let cells: Vec<_> = rows
+            .iter()
+            .map(|row| {
+                let mut cells = row.cells();
+                cells.remove(3);
+                cells
+            })
+            .collect();
+        assert_eq!(
+            cells,
+            [
+                [
+                    "broken",
+                    "-",
+                    "-",
+                    "line 2, column 3: expected a number, found \"x\""
+                ],
+                ["example", "114", "2", ""],
+            ]
+        );
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,445 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use common::Part;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2023;
+
+const USER_AGENT: &str = "aoc2023 runner (github.com/VincentWo/aoc2023)";
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession(PathBuf),
+    Status(u16, String),
+    Transport(String),
+    UnexpectedResponse(String),
+    Io(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession(path) => write!(
+                f,
+                "no session token: set AOC_SESSION or save the token in {}",
+                path.display()
+            ),
+            ClientError::Status(status, body) => {
+                write!(
+                    f,
+                    "the server answered with status {status}: {}",
+                    body.trim()
+                )
+            }
+            ClientError::Transport(err) => write!(f, "could not reach the server: {err}"),
+            ClientError::UnexpectedResponse(text) => {
+                write!(f, "could not make sense of the server's answer: {text}")
+            }
+            ClientError::Io(err) => err.fmt(f),
+        }
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(err: io::Error) -> Self {
+        ClientError::Io(err)
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(status, response) => {
+                ClientError::Status(status, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+        }
+    }
+}
+
+/// Where the session token is read from if `AOC_SESSION` is not set.
+pub fn session_path() -> PathBuf {
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .unwrap_or_default();
+
+    config.join("aoc").join("session")
+}
+
+/// Reads the session token from `AOC_SESSION` or, if that is not set, from [`session_path`].
+pub fn session() -> Result<String, ClientError> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let path = session_path();
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        Ok(_) => Err(ClientError::MissingSession(path)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Err(ClientError::MissingSession(path)),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Talks to the Advent of Code website, or to anything else serving the same paths.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{YEAR}/day/{day}{path}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(&self.url(day, "/input"))
+            .set("Cookie", &self.cookie())
+            .call()?;
+
+        Ok(response.into_string()?)
+    }
+
+    /// Submits `answer` for `part` of `day` and reports what the server made of it.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, ClientError> {
+        let response = self
+            .agent
+            .post(&self.url(day, "/answer"))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+
+        let text = article_text(&response.into_string()?);
+        Verdict::parse(&text).ok_or(ClientError::UnexpectedResponse(text))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// What the server made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// The answer is wrong; the server wants `wait` to pass before the next attempt.
+    Wrong {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    /// The answer was not checked because the last attempt was too recent.
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part is already solved, or not unlocked yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Reads the verdict from the text of the response page.
+    fn parse(text: &str) -> Option<Verdict> {
+        if text.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            let wait = between(text, "Please wait ", " before trying again").and_then(duration);
+
+            Some(Verdict::Wrong { hint, wait })
+        } else if text.contains("You gave an answer too recently") {
+            let wait = between(text, "You have ", " left to wait").and_then(duration)?;
+
+            Some(Verdict::RateLimited { wait })
+        } else if text.contains("You don't seem to be solving the right level") {
+            Some(Verdict::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+/// The text of the `<article>` of a page, or of the whole page if it has none, without markup.
+fn article_text(page: &str) -> String {
+    let article = between(page, "<article>", "</article>").unwrap_or(page);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (_, rest) = text.split_once(start)?;
+    let (between, _) = rest.split_once(end)?;
+    Some(between)
+}
+
+/// Parses the ways the server spells out durations, like `1m 23s`, `one minute` or `5 minutes`.
+fn duration(text: &str) -> Option<Duration> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+
+    spelled_duration(&words).or_else(|| compact_duration(&words))
+}
+
+fn spelled_duration(words: &[&str]) -> Option<Duration> {
+    let [amount, unit] = words else {
+        return None;
+    };
+
+    let amount = match *amount {
+        "a" | "an" | "one" => 1,
+        amount => amount.parse().ok()?,
+    };
+    let seconds = match unit.trim_end_matches('s') {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 60 * 60,
+        _ => return None,
+    };
+
+    Some(Duration::from_secs(amount * seconds))
+}
+
+fn compact_duration(words: &[&str]) -> Option<Duration> {
+    words
+        .iter()
+        .map(|word| {
+            let unit = word.chars().last()?;
+            let amount: u64 = word[..word.len() - unit.len_utf8()].parse().ok()?;
+            match unit {
+                'h' => Some(amount * 60 * 60),
+                'm' => Some(amount * 60),
+                's' => Some(amount),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+pub enum Fetched {
+    Downloaded(PathBuf),
+    Cached(PathBuf),
+}
+
+/// Downloads the input of `day` to `path`, unless it is already there.
+pub fn fetch(client: &Client, day: u8, path: &Path) -> Result<Fetched, ClientError> {
+    if path.exists() {
+        return Ok(Fetched::Cached(path.to_path_buf()));
+    }
+
+    let input = client.input(day)?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    // Write to a temporary file first, so an interrupted download never looks cached.
+    let partial = path.with_extension("partial");
+    fs::write(&partial, input)?;
+    fs::rename(partial, path)?;
+
+    Ok(Fetched::Downloaded(path.to_path_buf()))
+}
+
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// A request as seen by the stub server.
+    pub struct Request {
+        pub request_line: String,
+        pub headers: Vec<String>,
+        pub body: String,
+    }
+
+    /// Serves one canned `(status, body)` response per connection and returns the requests.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, response)| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                    let mut request_line = String::new();
+                    reader.read_line(&mut request_line).unwrap();
+
+                    let mut headers = Vec::new();
+                    loop {
+                        let mut header = String::new();
+                        reader.read_line(&mut header).unwrap();
+                        if header.trim().is_empty() {
+                            break;
+                        }
+                        headers.push(header.trim().to_string());
+                    }
+
+                    let length = headers
+                        .iter()
+                        .find_map(|header| {
+                            let (name, value) = header.split_once(':')?;
+                            name.eq_ignore_ascii_case("content-length")
+                                .then(|| value.trim().parse().unwrap())
+                        })
+                        .unwrap_or(0);
+                    let mut body = vec![0; length];
+                    reader.read_exact(&mut body).unwrap();
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\n\r\n{response}",
+                        response.len()
+                    )
+                    .unwrap();
+
+                    Request {
+                        request_line: request_line.trim().to_string(),
+                        headers,
+                        body: String::from_utf8(body).unwrap(),
+                    }
+                })
+                .collect()
+        });
+
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, process, time::Duration};
+
+    use super::{article_text, fetch, stub, Client, ClientError, Fetched, Hint, Verdict};
+
+    #[test]
+    fn fetching_an_input_once() {
+        let (base_url, server) = stub::serve(vec![(200, "1 2 3\n")]);
+        let client = Client::new(&base_url, "secret".to_string());
+        let path = env::temp_dir()
+            .join(format!("aoc-fetch-{}", process::id()))
+            .join("input");
+
+        assert!(matches!(
+            fetch(&client, 9, &path).unwrap(),
+            Fetched::Downloaded(_)
+        ));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+        // The stub only answers once, so this must not hit the network.
+        assert!(matches!(
+            fetch(&client, 9, &path).unwrap(),
+            Fetched::Cached(_)
+        ));
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].request_line, "GET /2023/day/9/input HTTP/1.1");
+        assert!(requests[0]
+            .headers
+            .iter()
+            .any(|header| header.eq_ignore_ascii_case("cookie: session=secret")));
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn failed_downloads_are_not_cached() {
+        let (base_url, server) = stub::serve(vec![(400, "Please log in.")]);
+        let client = Client::new(&base_url, "expired".to_string());
+        let path = env::temp_dir()
+            .join(format!("aoc-fetch-failed-{}", process::id()))
+            .join("input");
+
+        assert!(matches!(
+            fetch(&client, 1, &path),
+            Err(ClientError::Status(400, _))
+        ));
+        assert!(!path.exists());
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn reading_verdicts() {
+        let verdict = |page| Verdict::parse(&article_text(page));
+
+        assert_eq!(
+            verdict("<article><p>That's not the right answer.  Please wait 5 minutes before trying again.</p></article>"),
+            Some(Verdict::Wrong {
+                hint: None,
+                wait: Some(Duration::from_secs(300)),
+            })
+        );
+        assert_eq!(
+            verdict("<article><p>That's not the right answer; your answer is <em>too low</em>.</p></article>"),
+            Some(Verdict::Wrong {
+                hint: Some(Hint::TooLow),
+                wait: None,
+            })
+        );
+        assert_eq!(
+            verdict("<p>You gave an answer too recently.  You have 12s left to wait.</p>"),
+            Some(Verdict::RateLimited {
+                wait: Duration::from_secs(12),
+            })
+        );
+        assert_eq!(
+            verdict("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+            Some(Verdict::WrongLevel)
+        );
+        assert_eq!(verdict("<html>Puzzle inputs differ by user.</html>"), None);
+    }
+}
@@ -0,0 +1,173 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use common::{input, Part};
+
+use days::Day;
+
+/// An example input shipped with a day, together with the answers recorded for it.
+///
+/// The answers live in a sidecar file next to the example, e.g. `test_input.answers` for
+/// `test_input`, with one `part N: answer` line for every part the example is meant for.
+pub struct Example {
+    pub path: PathBuf,
+    pub answers: Vec<(Part, String)>,
+}
+
+impl Example {
+    fn read(path: PathBuf) -> io::Result<Option<Example>> {
+        let answers_path = path.with_extension("answers");
+        let answers = match fs::read_to_string(&answers_path) {
+            Ok(answers) => answers,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+
+        let answers = answers
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.strip_prefix("part ")
+                    .and_then(|line| line.split_once(':'))
+                    .and_then(|(part, answer)| Some((part.parse().ok()?, answer.trim().into())))
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!(
+                                "{}: expected `part N: answer`, found {line:?}",
+                                answers_path.display()
+                            ),
+                        )
+                    })
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(Some(Example { path, answers }))
+    }
+}
+
+/// Every example of `day` that has answers recorded next to it.
+pub fn examples(day: &Day) -> io::Result<Vec<Example>> {
+    let directory = input::day_directory(day.number);
+
+    let mut paths: Vec<_> = fs::read_dir(&directory)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<_>>()?;
+    paths.retain(|path| is_example(path));
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| Example::read(path).transpose())
+        .collect()
+}
+
+fn is_example(path: &Path) -> bool {
+    path.extension().is_none()
+        && path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("test_input"))
+}
+
+/// The result of running one part of a day against one of its examples.
+pub struct Outcome {
+    pub path: PathBuf,
+    pub part: Part,
+    pub expected: String,
+    pub actual: Result<String, String>,
+}
+
+impl Outcome {
+    pub fn is_match(&self) -> bool {
+        self.actual.as_ref() == Ok(&self.expected)
+    }
+}
+
+/// Runs every recorded part of every example of `day`.
+pub fn check(day: &Day) -> io::Result<Vec<Outcome>> {
+    let mut outcomes = Vec::new();
+
+    for example in examples(day)? {
+        let input = fs::read_to_string(&example.path)?;
+        outcomes.extend(run(day, &example, &input));
+    }
+
+    Ok(outcomes)
+}
+
+/// Runs every recorded part of `example` on `input`, which is usually the example itself.
+fn run(day: &Day, example: &Example, input: &str) -> Vec<Outcome> {
+    let parsed = day.solver.parse(input);
+
+    example
+        .answers
+        .iter()
+        .map(|(part, expected)| {
+            let actual = match &parsed {
+                Ok(parsed) => day.try_solve(*part, parsed).map(|solved| solved.answer),
+                Err(err) => Err(err.to_string()),
+            };
+
+            Outcome {
+                path: example.path.clone(),
+                part: *part,
+                expected: expected.clone(),
+                actual,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::{examples, run};
+
+    /// `input` the way a Windows editor might save it: with a BOM, CRLF line endings, trailing
+    /// spaces and more blank lines than needed.
+    fn untidy(input: &str) -> String {
+        let mut untidy = "\u{feff}".to_string();
+        for line in input.lines() {
+            if line.is_empty() {
+                untidy.push_str(" \r\n");
+            }
+            untidy.push_str(line);
+            untidy.push_str("  \r\n");
+        }
+        untidy + "\r\n\r\n"
+    }
+
+    #[test]
+    fn examples_match_their_answers() {
+        let mut checked = 0;
+
+        for day in days::all() {
+            for example in examples(&day).unwrap() {
+                let lf = fs::read_to_string(&example.path).unwrap();
+                let crlf = lf.replace('\n', "\r\n");
+                let untidy = untidy(&lf);
+
+                for (form, input) in [("LF", &lf), ("CRLF", &crlf), ("untidy", &untidy)] {
+                    for outcome in run(&day, &example, input) {
+                        assert!(
+                            outcome.is_match(),
+                            "day {} part {} on {} ({form}): expected {}, got {:?}",
+                            day.number,
+                            outcome.part,
+                            outcome.path.display(),
+                            outcome.expected,
+                            outcome.actual,
+                        );
+                        checked += 1;
+                    }
+                }
+            }
+        }
+
+        assert!(checked > 0, "no examples with recorded answers were found");
+    }
+}
@@ -0,0 +1,166 @@
+use std::{fs, io, path::Path};
+
+use common::Part;
+use serde::{Deserialize, Serialize};
+
+/// One answer a day produced for one input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Entry {
+    day: u8,
+    part: u8,
+    /// The [`input_hash`] of the input the answer was computed on.
+    input: String,
+    answer: String,
+    /// Whether the website accepted the answer.
+    verified: bool,
+    /// Seconds since the Unix epoch at which the answer was last computed or verified.
+    timestamp: u64,
+}
+
+/// Every answer computed so far, stored as JSON so that later runs can be compared against it.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    entries: Vec<Entry>,
+}
+
+/// A stable hash of `input`, so that history entries survive toolchain updates.
+///
+/// This is 64 bit FNV-1a, which is plenty to tell a handful of inputs apart.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    format!("{hash:016x}")
+}
+
+impl History {
+    /// Reads the history stored at `path`, or starts afresh if there is none yet.
+    pub fn load(path: &Path) -> io::Result<History> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {err}", path.display()),
+                )
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::from)?;
+        fs::write(path, json + "\n")
+    }
+
+    fn entry(&mut self, day: u8, part: Part, input: &str) -> Option<&mut Entry> {
+        let (part, input) = (u8::from(part), input_hash(input));
+
+        self.entries
+            .iter_mut()
+            .find(|entry| (entry.day, entry.part, &entry.input) == (day, part, &input))
+    }
+
+    /// Records that `answer` was computed at `now`.
+    ///
+    /// A verified answer is never replaced by a different one; if `answer` disagrees with it,
+    /// the verified answer is returned instead.
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: Part,
+        input: &str,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), String> {
+        match self.entry(day, part, input) {
+            Some(entry) if entry.verified && entry.answer != answer => Err(entry.answer.clone()),
+            Some(entry) => {
+                entry.answer = answer.to_string();
+                entry.timestamp = now;
+                Ok(())
+            }
+            None => {
+                self.push(day, part, input, answer, false, now);
+                Ok(())
+            }
+        }
+    }
+
+    /// Records that the website accepted `answer` at `now`.
+    pub fn verify(&mut self, day: u8, part: Part, input: &str, answer: &str, now: u64) {
+        match self.entry(day, part, input) {
+            Some(entry) => {
+                entry.answer = answer.to_string();
+                entry.verified = true;
+                entry.timestamp = now;
+            }
+            None => self.push(day, part, input, answer, true, now),
+        }
+    }
+
+    fn push(&mut self, day: u8, part: Part, input: &str, answer: &str, verified: bool, now: u64) {
+        self.entries.push(Entry {
+            day,
+            part: part.into(),
+            input: input_hash(input),
+            answer: answer.to_string(),
+            verified,
+            timestamp: now,
+        });
+        self.entries
+            .sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, process};
+
+    use common::Part;
+
+    use super::{input_hash, History};
+
+    #[test]
+    fn recording_answers() {
+        let path = env::temp_dir()
+            .join(format!("aoc-history-{}", process::id()))
+            .join("history.json");
+        let mut history = History::load(&path).unwrap();
+
+        assert_eq!(history.record(7, Part::Two, "input", "5904", 100), Ok(()));
+        // Unverified answers are simply replaced.
+        assert_eq!(history.record(7, Part::Two, "input", "5905", 200), Ok(()));
+
+        history.verify(7, Part::Two, "input", "5905", 300);
+        history.save(&path).unwrap();
+
+        let mut history = History::load(&path).unwrap();
+        assert_eq!(history.record(7, Part::Two, "input", "5905", 400), Ok(()));
+        assert_eq!(
+            history.record(7, Part::Two, "input", "5904", 500),
+            Err("5905".to_string())
+        );
+        // The disagreeing answer did not replace the verified one.
+        assert_eq!(
+            history.record(7, Part::Two, "input", "5903", 600),
+            Err("5905".to_string())
+        );
+
+        // Other inputs and parts are tracked separately.
+        assert_eq!(
+            history.record(7, Part::Two, "other input", "1", 600),
+            Ok(())
+        );
+        assert_eq!(history.record(7, Part::One, "input", "6440", 600), Ok(()));
+
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_ne!(input_hash("input"), input_hash("other input"));
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,118 @@
+use std::time::Duration;
+
+use common::{input::Source, Part};
+use serde::{Serialize, Serializer};
+
+use days::Day;
+
+use crate::{
+    history,
+    run::{DayRun, Phases},
+};
+
+/// The outcome of one part of one day, as printed by `--format json`.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub day: u8,
+    #[serde(serialize_with = "part_number")]
+    pub part: Part,
+    pub answer: Option<String>,
+    /// Where the input was read from.
+    pub input: String,
+    /// The [`history::input_hash`] of the input, if it could be read.
+    pub input_hash: Option<String>,
+    pub timings: Timings,
+    pub error: Option<String>,
+    /// What the solver pointed out about the input besides the answer.
+    pub warnings: Vec<String>,
+}
+
+fn part_number<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8((*part).into())
+}
+
+/// Wall times in milliseconds of the phases that were reached. Reading and parsing are shared
+/// by both parts of a day.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Timings {
+    pub read_ms: Option<f64>,
+    pub parse_ms: Option<f64>,
+    pub solve_ms: Option<f64>,
+}
+
+pub fn milliseconds(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+impl From<Phases> for Timings {
+    fn from(phases: Phases) -> Timings {
+        Timings {
+            read_ms: phases.read.map(milliseconds),
+            parse_ms: phases.parse.map(milliseconds),
+            solve_ms: phases.solve.map(milliseconds),
+        }
+    }
+}
+
+/// The records of a run of `day` on `source`, one per part.
+pub fn records(day: &Day, source: &Source, run: &DayRun) -> Vec<Record> {
+    let input_hash = run.input.as_deref().map(history::input_hash);
+
+    run.parts
+        .iter()
+        .map(|part| Record {
+            day: day.number,
+            part: part.part,
+            answer: part.answer.clone().ok(),
+            input: source.to_string(),
+            input_hash: input_hash.clone(),
+            timings: part.phases.into(),
+            error: part.answer.clone().err(),
+            warnings: part.warnings.clone(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use common::{
+        input::{self, Source},
+        Part,
+    };
+
+    use super::records;
+    use crate::run::run;
+
+    #[test]
+    fn serializing_records() {
+        let day = days::find(7).unwrap();
+        let source = Source::Path(input::day_directory(7).join("test_input"));
+
+        let solved = records(&day, &source, &run(&day, &source, &[Part::One, Part::Two]));
+        let json = serde_json::to_value(&solved[1]).unwrap();
+        assert_eq!(json["day"], 7);
+        assert_eq!(json["part"], 2);
+        assert_eq!(json["answer"], "5905");
+        assert_eq!(json["error"], serde_json::Value::Null);
+        assert_eq!(json["warnings"], serde_json::json!([]));
+        assert!(json["timings"]["solve_ms"].is_f64());
+
+        let day = days::find(1).unwrap();
+        let source = Source::Path(input::day_directory(1).join("test_input2"));
+        let warned = records(&day, &source, &run(&day, &source, &[Part::One]));
+        let json = serde_json::to_value(&warned[0]).unwrap();
+        assert_eq!(json["answer"], "209");
+        assert_eq!(
+            json["warnings"],
+            serde_json::json!(["line 2 has no digit and is left out of the sum"])
+        );
+
+        let missing = Source::Path(input::day_directory(7).join("no_such_input"));
+        let failed = records(&day, &missing, &run(&day, &missing, &[Part::One]));
+        let json = serde_json::to_value(&failed[0]).unwrap();
+        assert_eq!(json["answer"], serde_json::Value::Null);
+        assert_eq!(json["input_hash"], serde_json::Value::Null);
+        assert_eq!(json["timings"]["parse_ms"], serde_json::Value::Null);
+        assert!(json["error"].as_str().unwrap().contains("no_such_input"));
+    }
+}
@@ -0,0 +1,719 @@
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use common::{
+    diagnostics,
+    input::{self, Source},
+    Parsed, Part, Solved,
+};
+
+use client::{Client, ClientError, Fetched, Verdict};
+use days::Day;
+use history::History;
+use server::{Limits, Server};
+
+mod batch;
+mod client;
+mod examples;
+mod history;
+mod json;
+mod repl;
+mod run;
+mod scaffold;
+mod server;
+mod submissions;
+mod table;
+mod timing;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Print the diagnostics of the solvers selected by this filter, e.g. `debug` or
+    /// `day07=debug,day10=trace`
+    #[arg(long, global = true, env = diagnostics::ENV)]
+    log: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solutions of a single day or of all days
+    Run(RunArgs),
+
+    /// Runs a day on every file in a directory and prints a table of the answers
+    Batch {
+        day: u8,
+
+        /// The directory with the inputs
+        directory: PathBuf,
+    },
+
+    /// Checks the solutions against the example inputs and their recorded answers
+    Check {
+        /// Only check this day
+        day: Option<u8>,
+    },
+
+    /// Downloads the puzzle input of a day to `dayNN/src/input`, unless it is already there
+    Fetch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The server to download from
+        #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+
+    /// Submits the answer of a part, refusing answers that are already known to be wrong
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        part: Part,
+
+        /// The answer to submit, instead of solving the part on `dayNN/src/input`
+        answer: Option<String>,
+
+        /// The server to submit to
+        #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+
+    /// Parses the input of a day once and answers queries about it in an interactive shell
+    Repl {
+        day: u8,
+
+        /// Read the input from this file, or from stdin if it is `-`, instead of `dayNN/src/input`
+        #[arg(long, short)]
+        input: Option<String>,
+    },
+
+    /// Serves the solvers over HTTP: `POST /day/<day>/part/<part>` with the input as the body
+    /// answers with JSON
+    Serve {
+        /// The address to listen on
+        #[arg(long, default_value = "127.0.0.1:8023")]
+        address: String,
+
+        /// How many seconds a request may take before it is answered with a timeout error
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        timeout: u64,
+
+        /// The largest input accepted, in KiB; larger ones are answered with an error
+        #[arg(long, default_value_t = 1024, value_parser = clap::value_parser!(u64).range(1..))]
+        max_input_kib: u64,
+
+        /// How many solvers may run at once; requests beyond that are answered with an error
+        #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u64).range(1..))]
+        max_solvers: u64,
+    },
+
+    /// Creates the crate of a new day from a template and adds it to the workspace
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// The day to run
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Only run this part
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// Run every day and print a summary table
+    #[arg(long)]
+    all: bool,
+
+    /// Read the input from this file, or from stdin if it is `-`, instead of `dayNN/src/input`
+    #[arg(long, short, conflicts_with = "all")]
+    input: Option<String>,
+
+    /// Measure how long reading, parsing and each part take instead of printing the answers
+    #[arg(long)]
+    time: bool,
+
+    /// How often every phase is run when timing
+    #[arg(long, default_value_t = 10, requires = "time", value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Warn and fail if an answer disagrees with the one verified for the same input
+    #[arg(long, conflicts_with = "time")]
+    check_history: bool,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t = Format::Text, conflicts_with = "time")]
+    format: Format,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Lines meant for people, or a table with `--all`
+    Text,
+    /// One JSON object per line for every day and part, with timings and any error
+    Json,
+}
+
+impl RunArgs {
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+/// Seconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Where `aoc` keeps the state it builds up across runs, like the answer history.
+fn state_path(name: &str) -> PathBuf {
+    input::workspace_root().join(".aoc").join(name)
+}
+
+/// Reads and parses the input of `day`, reporting any problem on stderr.
+fn load(day: &Day, source: &Source) -> Option<(String, Parsed)> {
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return None;
+        }
+    };
+
+    match day.solver.parse(&input) {
+        Ok(parsed) => Some((input, parsed)),
+        Err(err) => {
+            eprintln!("{}", err.report(source, &input));
+            None
+        }
+    }
+}
+
+/// The answer history, or `None` after reporting why it could not be read.
+fn open_history() -> Option<History> {
+    match History::load(&state_path("history.json")) {
+        Ok(history) => Some(history),
+        Err(err) => {
+            eprintln!("warning: not recording answers, the history could not be read: {err}");
+            None
+        }
+    }
+}
+
+fn save_history(history: &History) {
+    if let Err(err) = history.save(&state_path("history.json")) {
+        eprintln!("warning: could not save the answer history: {err}");
+    }
+}
+
+/// Records the answers of `day` on `input`; returns `false` if one disagrees with a verified
+/// answer and `check` is set.
+fn record(
+    history: &mut Option<History>,
+    day: &Day,
+    input: &str,
+    answers: &[(Part, String)],
+    check: bool,
+) -> bool {
+    let Some(history) = history else {
+        return true;
+    };
+
+    let mut agrees = true;
+    for (part, answer) in answers {
+        if let Err(verified) = history.record(day.number, *part, input, answer, now()) {
+            if check {
+                eprintln!(
+                    "warning: Day {:02} part {part}: got {answer}, but {verified} was verified \
+                     for this input",
+                    day.number
+                );
+                agrees = false;
+            }
+        }
+    }
+    agrees
+}
+
+/// Solves `parts`, or describes the first part whose solver panicked.
+fn solve(day: &Day, parts: &[Part], parsed: &Parsed) -> Result<Vec<(Part, Solved)>, String> {
+    parts
+        .iter()
+        .map(|&part| {
+            day.try_solve(part, parsed)
+                .map(|solved| (part, solved))
+                .map_err(|err| format!("day {:02} part {part}: {err}", day.number))
+        })
+        .collect()
+}
+
+/// Prints the warnings of `part` of `day` to stderr and keeps only its answer.
+fn answer(day: &Day, part: Part, solved: Solved) -> String {
+    for warning in &solved.warnings {
+        eprintln!("warning: Day {:02} part {part}: {warning}", day.number);
+    }
+    solved.answer
+}
+
+fn answers(day: &Day, solved: Vec<(Part, Solved)>) -> Vec<(Part, String)> {
+    solved
+        .into_iter()
+        .map(|(part, solved)| (part, answer(day, part, solved)))
+        .collect()
+}
+
+fn run_day(day: &Day, source: Source, parts: &[Part], check_history: bool) -> ExitCode {
+    let Some((input, parsed)) = load(day, &source) else {
+        return ExitCode::FAILURE;
+    };
+
+    let answers = match solve(day, parts, &parsed) {
+        Ok(solved) => answers(day, solved),
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for (part, answer) in &answers {
+        println!("Day {:02} part {part}: {answer}", day.number);
+    }
+
+    let mut history = open_history();
+    let agrees = record(&mut history, day, &input, &answers, check_history);
+    if let Some(history) = &history {
+        save_history(history);
+    }
+
+    if agrees {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run_json(days: &[(Day, Source)], parts: &[Part], check_history: bool) -> ExitCode {
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut history = open_history();
+
+    for (day, source) in days {
+        let run = run::run(day, source, parts);
+
+        for record in &json::records(day, source, &run) {
+            match serde_json::to_string(record) {
+                Ok(json) => println!("{json}"),
+                Err(err) => eprintln!("error: could not serialize a result: {err}"),
+            }
+            if record.error.is_some() {
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+
+        if let Some(input) = &run.input {
+            if !record(&mut history, day, input, &run.answers(), check_history) {
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if let Some(history) = &history {
+        save_history(history);
+    }
+
+    exit_code
+}
+
+fn run_timed(days: &[(Day, Source)], parts: &[Part], runs: usize) -> ExitCode {
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut timings = Vec::new();
+
+    for (day, source) in days {
+        match timing::measure(day, source, parts, runs) {
+            Ok(day_timings) => timings.push(day_timings),
+            Err(err) => {
+                eprintln!("Day {:02}: {err}", day.number);
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    timing::print(&timings, runs);
+
+    exit_code
+}
+
+fn run_all(parts: &[Part], check_history: bool) -> ExitCode {
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut history = open_history();
+
+    let rows: Vec<Vec<String>> = days::all()
+        .iter()
+        .map(|day| {
+            let mut row = vec![format!("{:02}", day.number)];
+
+            match load(day, &Source::Default(day.number)) {
+                Some((input, parsed)) => match solve(day, parts, &parsed) {
+                    Ok(solved) => {
+                        let answers = answers(day, solved);
+                        if !record(&mut history, day, &input, &answers, check_history) {
+                            exit_code = ExitCode::FAILURE;
+                        }
+                        row.extend(answers.into_iter().map(|(_, answer)| answer));
+                    }
+                    Err(err) => {
+                        eprintln!("error: {err}");
+                        exit_code = ExitCode::FAILURE;
+                        row.extend(parts.iter().map(|_| "-".to_string()));
+                    }
+                },
+                None => {
+                    exit_code = ExitCode::FAILURE;
+                    row.extend(parts.iter().map(|_| "-".to_string()));
+                }
+            }
+
+            row
+        })
+        .collect();
+
+    let header: Vec<String> = ["Day".to_string()]
+        .into_iter()
+        .chain(parts.iter().map(|part| format!("Part {part}")))
+        .collect();
+
+    table::print(&header, &rows);
+
+    if let Some(history) = &history {
+        save_history(history);
+    }
+
+    exit_code
+}
+
+fn batch(number: u8, directory: &Path) -> ExitCode {
+    let Some(day) = days::find(number) else {
+        eprintln!("Day {number} is not solved yet");
+        return ExitCode::FAILURE;
+    };
+
+    let rows = match batch::run(&day, directory) {
+        Ok(rows) => rows,
+        Err(err) => {
+            eprintln!("error: could not read {}: {err}", directory.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let header = ["File", "Part 1", "Part 2", "Time", "Error"].map(String::from);
+    table::print(
+        &header,
+        &rows.iter().map(batch::Row::cells).collect::<Vec<_>>(),
+    );
+    for row in &rows {
+        for (part, warning) in &row.warnings {
+            eprintln!("warning: {} part {part}: {warning}", row.path.display());
+        }
+    }
+
+    if rows.iter().any(|row| row.error.is_some()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn check(day: Option<u8>) -> ExitCode {
+    let days = match day {
+        Some(number) => match days::find(number) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("Day {number} is not solved yet");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => days::all(),
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+
+    for day in days {
+        let outcomes = match examples::check(&day) {
+            Ok(outcomes) => outcomes,
+            Err(err) => {
+                eprintln!(
+                    "error: could not check the examples of day {:02}: {err}",
+                    day.number
+                );
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        for outcome in outcomes {
+            let name = outcome
+                .path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy();
+            let label = format!("Day {:02} {name} part {}", day.number, outcome.part);
+
+            if outcome.is_match() {
+                println!("{label}: ok");
+            } else {
+                exit_code = ExitCode::FAILURE;
+                match outcome.actual {
+                    Ok(actual) => println!("{label}: expected {}, got {actual}", outcome.expected),
+                    Err(err) => println!("{label}: expected {}, but {err}", outcome.expected),
+                }
+            }
+        }
+    }
+
+    exit_code
+}
+
+fn fetch(day: u8, base_url: &str) -> Result<(), ClientError> {
+    let path = input::default_path(day);
+
+    // Check the cache before asking for a session, so cached inputs work without one.
+    let fetched = if path.exists() {
+        Fetched::Cached(path)
+    } else {
+        let client = Client::new(base_url, client::session()?);
+        client::fetch(&client, day, &path)?
+    };
+
+    match fetched {
+        Fetched::Downloaded(path) => println!("Day {day:02}: downloaded to {}", path.display()),
+        Fetched::Cached(path) => println!("Day {day:02}: already have {}", path.display()),
+    }
+
+    Ok(())
+}
+
+fn submit(day: u8, part: Part, answer: Option<String>, base_url: &str) -> ExitCode {
+    // The input is only needed to record a correct answer in the history, so an explicit
+    // answer can be submitted without one.
+    let (answer, input) = match answer {
+        Some(answer) => (answer, Source::Default(day).read().ok()),
+        None => {
+            let Some(solved) = days::find(day) else {
+                eprintln!("Day {day} is not solved yet, pass the answer explicitly");
+                return ExitCode::FAILURE;
+            };
+            let Some((input, parsed)) = load(&solved, &Source::Default(day)) else {
+                return ExitCode::FAILURE;
+            };
+            match solved.try_solve(part, &parsed) {
+                Ok(answer) => (self::answer(&solved, part, answer), Some(input)),
+                Err(err) => {
+                    eprintln!("error: day {day:02} part {part}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+
+    let client = match client::session() {
+        Ok(session) => Client::new(base_url, session),
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let path = state_path("submissions.json");
+
+    let label = format!("Day {day:02} part {part}: {answer}");
+    match submissions::submit(&client, &path, day, part, &answer, now()) {
+        Ok(Verdict::Correct) => {
+            println!("{label} is correct");
+            if let (Some(input), Some(mut history)) = (input, open_history()) {
+                history.verify(day, part, &input, &answer, now());
+                save_history(&history);
+            }
+            ExitCode::SUCCESS
+        }
+        Ok(Verdict::Wrong { hint, wait }) => {
+            match hint {
+                Some(hint) => print!("{label} is wrong, it is {hint}"),
+                None => print!("{label} is wrong"),
+            }
+            match wait {
+                Some(wait) => println!("; wait {}s before trying again", wait.as_secs()),
+                None => println!(),
+            }
+            ExitCode::FAILURE
+        }
+        Ok(Verdict::RateLimited { wait }) => {
+            println!(
+                "{label} was not checked, the last answer was too recent; wait {}s",
+                wait.as_secs()
+            );
+            ExitCode::FAILURE
+        }
+        Ok(Verdict::WrongLevel) => {
+            println!("{label} was not checked, the part is already solved or still locked");
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn explore(day: u8, source: Source) -> ExitCode {
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let session = match repl::Session::load(day, &input) {
+        Some(Ok(session)) => session,
+        Some(Err(err)) => {
+            eprintln!("{}", err.report(&source, &input));
+            return ExitCode::FAILURE;
+        }
+        None => {
+            eprintln!("Day {day} is not solved yet");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match repl::run(day, &session, &state_path("repl_history")) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn serve(address: &str, limits: Limits) -> ExitCode {
+    let server = match Server::bind(address, days::all(), limits) {
+        Ok(server) => server,
+        Err(err) => {
+            eprintln!("error: could not listen on {address}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("Listening on http://{}", server.local_addr());
+
+    match server.run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    if let Err(err) = diagnostics::init(cli.log.as_deref()) {
+        eprintln!("error: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    match cli.command {
+        Command::Run(args) => {
+            let parts = args.parts();
+
+            if args.all && !args.time && args.format == Format::Text {
+                return run_all(&parts, args.check_history);
+            }
+
+            let days = if args.all {
+                days::all()
+                    .into_iter()
+                    .map(|day| {
+                        let source = Source::Default(day.number);
+                        (day, source)
+                    })
+                    .collect()
+            } else {
+                let number = args.day.expect("clap requires a day unless --all is given");
+                let Some(day) = days::find(number) else {
+                    eprintln!("Day {number} is not solved yet");
+                    return ExitCode::FAILURE;
+                };
+                vec![(day, Source::from_arg(number, args.input.as_deref()))]
+            };
+
+            if args.time {
+                run_timed(&days, &parts, args.runs as usize)
+            } else if args.format == Format::Json {
+                run_json(&days, &parts, args.check_history)
+            } else {
+                let (day, source) = days.into_iter().next().unwrap();
+                run_day(&day, source, &parts, args.check_history)
+            }
+        }
+        Command::Batch { day, directory } => batch(day, &directory),
+        Command::Check { day } => check(day),
+        Command::Fetch { day, base_url } => match fetch(day, &base_url) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("error: {err}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Submit {
+            day,
+            part,
+            answer,
+            base_url,
+        } => submit(day, part, answer, &base_url),
+        Command::Repl { day, input } => explore(day, Source::from_arg(day, input.as_deref())),
+        Command::Serve {
+            address,
+            timeout,
+            max_input_kib,
+            max_solvers,
+        } => {
+            let limits = Limits {
+                timeout: Duration::from_secs(timeout),
+                max_input: usize::try_from(max_input_kib.saturating_mul(1024))
+                    .unwrap_or(usize::MAX),
+                max_solvers: usize::try_from(max_solvers).unwrap_or(usize::MAX),
+            };
+            serve(&address, limits)
+        }
+        Command::New { day } => match scaffold::create(&input::workspace_root(), day) {
+            Ok(directory) => {
+                println!(
+                    "Created {} and added it to the workspace and to days/.",
+                    directory.display()
+                );
+                println!(
+                    "Queries for `aoc repl` go in its `Solution::COMMANDS` and \
+                     `Solution::query`; Python classes for its input can be added in python/src/."
+                );
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("error: {err}");
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
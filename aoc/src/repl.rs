@@ -0,0 +1,245 @@
+use std::{fs, path::Path};
+
+use common::{
+    query::{arguments, Command},
+    ParseError, Parsed,
+};
+use days::Day;
+use rustyline::{
+    completion::{Completer, Pair},
+    error::ReadlineError,
+    highlight::Highlighter,
+    hint::Hinter,
+    history::FileHistory,
+    validate::Validator,
+    Context, Editor, Helper,
+};
+
+/// The commands of every day.
+const BUILTIN: &[Command] = &[
+    Command {
+        name: "help",
+        arguments: "",
+        help: "list the commands",
+    },
+    Command {
+        name: "part",
+        arguments: "<1|2>",
+        help: "solve a part",
+    },
+    Command {
+        name: "quit",
+        arguments: "",
+        help: "leave the shell",
+    },
+];
+
+/// A day's input, parsed once and then queried.
+pub struct Session {
+    day: Day,
+    parsed: Parsed,
+}
+
+impl Session {
+    /// Parses `input` for `day`, or returns `None` if there is no such day.
+    pub fn load(day: u8, input: &str) -> Option<Result<Session, ParseError>> {
+        let day = days::find(day)?;
+
+        Some(
+            day.solver
+                .parse(input)
+                .map(|parsed| Session { day, parsed }),
+        )
+    }
+
+    fn commands(&self) -> impl Iterator<Item = &Command> {
+        BUILTIN.iter().chain(self.day.solver.commands())
+    }
+
+    /// Answers `line`, or returns `None` if it asks to quit.
+    pub fn eval(&self, line: &str) -> Option<Result<String, String>> {
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or_default();
+        let args: Vec<_> = words.collect();
+
+        let answer = days::catch_panic(|| match command {
+            "quit" | "exit" => None,
+            "help" => Some(Ok(self
+                .commands()
+                .map(|command| {
+                    let usage = format!("{} {}", command.name, command.arguments);
+                    format!("{:<24}{}", usage.trim_end(), command.help)
+                })
+                .collect::<Vec<_>>()
+                .join("\n"))),
+            "part" => Some(arguments(&args, "part <1|2>").and_then(|[part]| {
+                let part = part
+                    .parse()
+                    .map_err(|_| format!("there is no part {part}"))?;
+                let solved = self.day.solver.solve(part, &self.parsed);
+                for warning in &solved.warnings {
+                    eprintln!("warning: part {part}: {warning}");
+                }
+                Ok(solved.answer)
+            })),
+            command if self.day.solver.commands().iter().any(|c| c.name == command) => {
+                Some(self.day.solver.query(&self.parsed, command, &args))
+            }
+            command => Some(Err(format!("unknown command {command:?}, try `help`"))),
+        });
+
+        // A panic is reported as the error of the query that caused it.
+        answer.unwrap_or_else(|message| Some(Err(message)))
+    }
+}
+
+/// Completes the names of the commands.
+struct CommandCompleter {
+    names: Vec<&'static str>,
+}
+
+impl Completer for CommandCompleter {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let prefix = &line[..pos];
+        if prefix.contains(char::is_whitespace) {
+            return Ok((pos, Vec::new()));
+        }
+
+        let candidates = self
+            .names
+            .iter()
+            .filter(|name| name.starts_with(prefix))
+            .map(|name| Pair {
+                display: name.to_string(),
+                replacement: format!("{name} "),
+            })
+            .collect();
+
+        Ok((0, candidates))
+    }
+}
+
+impl Hinter for CommandCompleter {
+    type Hint = String;
+}
+
+impl Highlighter for CommandCompleter {}
+
+impl Validator for CommandCompleter {}
+
+impl Helper for CommandCompleter {}
+
+/// Reads queries from the terminal until `quit`, keeping the lines entered in `history_path`.
+pub fn run(day: u8, session: &Session, history_path: &Path) -> rustyline::Result<()> {
+    let mut editor = Editor::<CommandCompleter, FileHistory>::new()?;
+    editor.set_helper(Some(CommandCompleter {
+        names: session.commands().map(|command| command.name).collect(),
+    }));
+    // There is no history before the first session.
+    let _ = editor.load_history(history_path);
+
+    println!("Day {day} is loaded. Type `help` for the commands.");
+    read_queries(day, session, &mut editor)?;
+
+    if let Some(parent) = history_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    editor.save_history(history_path)
+}
+
+fn read_queries(
+    day: u8,
+    session: &Session,
+    editor: &mut Editor<CommandCompleter, FileHistory>,
+) -> rustyline::Result<()> {
+    loop {
+        let line = match editor.readline(&format!("day{day:02}> ")) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        editor.add_history_entry(line.as_str())?;
+
+        match session.eval(&line) {
+            Some(Ok(answer)) => println!("{answer}"),
+            Some(Err(err)) => eprintln!("error: {err}"),
+            None => return Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::input::{self, Source};
+
+    use super::Session;
+
+    fn session(day: u8, example: &str) -> Session {
+        let path = input::day_directory(day).join(example);
+        let input = Source::Path(path).read().unwrap();
+
+        Session::load(day, &input).unwrap().unwrap()
+    }
+
+    fn eval(session: &Session, line: &str) -> Result<String, String> {
+        session.eval(line).unwrap()
+    }
+
+    #[test]
+    fn querying_examples() {
+        let day04 = session(4, "test_input");
+        assert_eq!(
+            eval(&day04, "card 1"),
+            Ok("Card 1\n\
+                winning numbers: 17 41 48 83 86\n\
+                numbers: 6 9 17 31 48 53 83 86\n\
+                matches: 4, worth 8 points"
+                .to_string())
+        );
+        assert_eq!(eval(&day04, "part 2"), Ok("30".to_string()));
+        assert_eq!(eval(&day04, "card"), Err("usage: card <id>".to_string()));
+        assert_eq!(
+            eval(&day04, "card 7"),
+            Err("there is no card 7".to_string())
+        );
+
+        let day05 = session(5, "test_input");
+        let trace = eval(&day05, "trace 79").unwrap();
+        assert!(
+            trace.starts_with("seed 79 -> soil 81 by rule 50..98 +2\n"),
+            "{trace}"
+        );
+        assert!(trace.ends_with("location 82"), "{trace}");
+
+        let day07 = session(7, "test_input");
+        assert_eq!(
+            eval(&day07, "kind KTJJT"),
+            Ok("TwoPairs, or FourOf with jokers".to_string())
+        );
+
+        let day08 = session(8, "test_input");
+        assert_eq!(eval(&day08, "node AAA"), Ok("AAA = (BBB, BBB)".to_string()));
+
+        assert!(eval(&day08, "help").unwrap().contains("node <name>"));
+        assert_eq!(
+            eval(&day08, "frobnicate"),
+            Err("unknown command \"frobnicate\", try `help`".to_string())
+        );
+        assert!(day08.eval("quit").is_none());
+        // The example of part one does not have the shape part two relies on.
+        assert!(eval(&day08, "part 2")
+            .unwrap_err()
+            .starts_with("the solver panicked"));
+    }
+}
@@ -0,0 +1,139 @@
+use std::time::Duration;
+
+use common::{input::Source, Part};
+
+use days::Day;
+
+use crate::timing::time;
+
+/// Wall times of the phases that were reached. Reading and parsing are shared by both parts of a
+/// day.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Phases {
+    pub read: Option<Duration>,
+    pub parse: Option<Duration>,
+    pub solve: Option<Duration>,
+}
+
+/// The answer to one part, or what went wrong on the way to it.
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Result<String, String>,
+    /// What the solver pointed out about the input, see [`common::Solution::warnings`].
+    pub warnings: Vec<String>,
+    pub phases: Phases,
+}
+
+/// The parts of a single day, together with its input for the answer history.
+pub struct DayRun {
+    pub input: Option<String>,
+    pub parts: Vec<PartRun>,
+}
+
+impl DayRun {
+    pub fn answers(&self) -> Vec<(Part, String)> {
+        self.parts
+            .iter()
+            .filter_map(|run| Some((run.part, run.answer.clone().ok()?)))
+            .collect()
+    }
+}
+
+/// Runs `parts` of `day` once, timing every phase. A problem that stops a phase becomes the
+/// error of every part it affects.
+pub fn run(day: &Day, source: &Source, parts: &[Part]) -> DayRun {
+    let failed = |phases: Phases, error: String| {
+        parts
+            .iter()
+            .map(|&part| PartRun {
+                part,
+                answer: Err(error.clone()),
+                warnings: Vec::new(),
+                phases,
+            })
+            .collect()
+    };
+
+    let mut phases = Phases::default();
+
+    let (input, duration) = time(|| source.read());
+    phases.read = Some(duration);
+    let input = match input {
+        Ok(input) => input,
+        Err(err) => {
+            return DayRun {
+                input: None,
+                parts: failed(phases, err.to_string()),
+            }
+        }
+    };
+
+    let (parsed, duration) = time(|| day.solver.parse(&input));
+    phases.parse = Some(duration);
+
+    let parts = match parsed {
+        Ok(parsed) => parts
+            .iter()
+            .map(|&part| {
+                let (solved, duration) = time(|| day.try_solve(part, &parsed));
+                let phases = Phases {
+                    solve: Some(duration),
+                    ..phases
+                };
+                let (answer, warnings) = match solved {
+                    Ok(solved) => (Ok(solved.answer), solved.warnings),
+                    Err(err) => (Err(err), Vec::new()),
+                };
+
+                PartRun {
+                    part,
+                    answer,
+                    warnings,
+                    phases,
+                }
+            })
+            .collect(),
+        Err(err) => failed(phases, err.to_string()),
+    };
+
+    DayRun {
+        input: Some(input),
+        parts,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::{
+        input::{self, Source},
+        Part,
+    };
+
+    use super::run;
+
+    #[test]
+    fn running_a_day() {
+        let day = days::find(7).unwrap();
+        let source = Source::Path(input::day_directory(7).join("test_input"));
+
+        let solved = run(&day, &source, &[Part::One, Part::Two]);
+        assert_eq!(
+            solved.answers(),
+            [
+                (Part::One, "6440".to_string()),
+                (Part::Two, "5905".to_string())
+            ]
+        );
+        assert!(solved.parts[1].phases.solve.is_some());
+
+        let missing = Source::Path(input::day_directory(7).join("no_such_input"));
+        let failed = run(&day, &missing, &[Part::One]);
+        assert!(failed.input.is_none());
+        assert!(failed.parts[0]
+            .answer
+            .as_ref()
+            .unwrap_err()
+            .contains("no_such_input"));
+        assert!(failed.parts[0].phases.parse.is_none());
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const TEMPLATES: [(&str, &str); 4] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml")),
+    ("src/lib.rs", include_str!("../templates/lib.rs")),
+    ("src/main.rs", include_str!("../templates/main.rs")),
+    ("src/test_input", ""),
+];
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    AlreadyExists(PathBuf),
+    NoMembers(PathBuf),
+    /// The `[dependencies]` of the `days` crate are missing.
+    NoDependencies(PathBuf),
+    /// The list in `days::all` is missing.
+    NoDayList(PathBuf),
+    Io(io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(path) => {
+                write!(
+                    f,
+                    "{} already exists, refusing to overwrite it",
+                    path.display()
+                )
+            }
+            ScaffoldError::NoMembers(path) => {
+                write!(f, "could not find the `members` list in {}", path.display())
+            }
+            ScaffoldError::NoDependencies(path) => {
+                write!(f, "could not find `[dependencies]` in {}", path.display())
+            }
+            ScaffoldError::NoDayList(path) => {
+                write!(
+                    f,
+                    "could not find the list of `all()` in {}",
+                    path.display()
+                )
+            }
+            ScaffoldError::Io(err) => err.fmt(f),
+        }
+    }
+}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(err: io::Error) -> Self {
+        ScaffoldError::Io(err)
+    }
+}
+
+/// Creates the crate of `day` in the workspace at `root`, adds it to the workspace members and
+/// registers it in the `days` crate, so that `aoc` can run it.
+pub fn create(root: &Path, day: u8) -> Result<PathBuf, ScaffoldError> {
+    let name = format!("day{day:02}");
+    let directory = root.join(&name);
+
+    if directory.exists() {
+        return Err(ScaffoldError::AlreadyExists(directory));
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = add_member(&fs::read_to_string(&manifest_path)?, &name)
+        .ok_or_else(|| ScaffoldError::NoMembers(manifest_path.clone()))?;
+    let days_manifest_path = root.join("days/Cargo.toml");
+    let days_manifest = add_dependency(&fs::read_to_string(&days_manifest_path)?, &name)
+        .ok_or_else(|| ScaffoldError::NoDependencies(days_manifest_path.clone()))?;
+    let days_path = root.join("days/src/lib.rs");
+    let days = add_day(&fs::read_to_string(&days_path)?, day)
+        .ok_or_else(|| ScaffoldError::NoDayList(days_path.clone()))?;
+
+    fs::create_dir_all(directory.join("src"))?;
+    for (path, template) in TEMPLATES {
+        let contents = template
+            .replace("{{NN}}", &format!("{day:02}"))
+            .replace("{{N}}", &day.to_string());
+        fs::write(directory.join(path), contents)?;
+    }
+
+    fs::write(manifest_path, manifest)?;
+    fs::write(days_manifest_path, days_manifest)?;
+    fs::write(days_path, days)?;
+
+    Ok(directory)
+}
+
+/// The offset of every line in `text` that starts with `key`, optionally indented.
+fn lines_starting_with<'a>(text: &'a str, key: &'a str) -> impl Iterator<Item = usize> + 'a {
+    std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+        .filter(move |&i| text[i..].trim_start_matches([' ', '\t']).starts_with(key))
+}
+
+/// Adds `name` to the `members` list of a workspace manifest.
+fn add_member(manifest: &str, name: &str) -> Option<String> {
+    // Only the key itself counts, not `default-members` or a comment that mentions it.
+    let start = lines_starting_with(manifest, "members").find(|&i| {
+        manifest[i..]
+            .trim_start()
+            .trim_start_matches("members")
+            .trim_start()
+            .starts_with('=')
+    })?;
+    let open = start + manifest[start..].find('[')?;
+    let close = open + manifest[open..].find(']')?;
+
+    let mut members: Vec<&str> = manifest[open + 1..close]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .collect();
+
+    if !members.contains(&name) {
+        members.push(name);
+    }
+
+    let members = members
+        .iter()
+        .map(|member| format!("{member:?}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Some(format!(
+        "{}[{members}]{}",
+        &manifest[..open],
+        &manifest[close + 1..]
+    ))
+}
+
+/// Adds a path dependency on the crate `name` at the end of the `[dependencies]` of a manifest.
+fn add_dependency(manifest: &str, name: &str) -> Option<String> {
+    let section = lines_starting_with(manifest, "[dependencies]").next()?;
+    // The section ends where the next one starts.
+    let end = lines_starting_with(manifest, "[")
+        .find(|&i| i > section)
+        .unwrap_or(manifest.len());
+    let body = manifest[..end].trim_end_matches('\n');
+
+    let rest = &manifest[body.len()..];
+
+    Some(format!(
+        "{body}\n{name} = {{ path = \"../{name}\" }}\n{}",
+        rest.strip_prefix('\n').unwrap_or(rest)
+    ))
+}
+
+/// Adds `day` to the end of the list returned by `days::all`.
+fn add_day(source: &str, day: u8) -> Option<String> {
+    let start = source.find("pub fn all() -> Vec<Day> {")?;
+    let close = start + lines_starting_with(&source[start..], "]").next()?;
+
+    Some(format!(
+        "{}        Day::of::<day{day:02}::Day{day:02}>({day}),\n{}",
+        &source[..close],
+        &source[close..]
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, process};
+
+    use super::{create, ScaffoldError};
+
+    #[test]
+    fn creating_a_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        fs::create_dir_all(root.join("day01")).unwrap();
+        fs::create_dir_all(root.join("days/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\n# The members [are sorted]\ndefault-members = [\"aoc\"]\n\
+             members = [\"aoc\", \"day01\"]\nresolver = \"1\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("days/Cargo.toml"),
+            "[package]\nname = \"days\"\n\n[dependencies]\nday01 = { path = \"../day01\" }\n\n\
+             [dev-dependencies]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("days/src/lib.rs"),
+            "pub fn all() -> Vec<Day> {\n    vec![\n        Day::of::<day01::Day01>(1),\n    ]\n}\n",
+        )
+        .unwrap();
+
+        let directory = create(&root, 11).unwrap();
+
+        assert_eq!(directory, root.join("day11"));
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "[workspace]\n# The members [are sorted]\ndefault-members = [\"aoc\"]\n\
+             members = [\"aoc\", \"day01\", \"day11\"]\nresolver = \"1\"\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("days/Cargo.toml")).unwrap(),
+            "[package]\nname = \"days\"\n\n[dependencies]\nday01 = { path = \"../day01\" }\n\
+             day11 = { path = \"../day11\" }\n\n[dev-dependencies]\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("days/src/lib.rs")).unwrap(),
+            "pub fn all() -> Vec<Day> {\n    vec![\n        Day::of::<day01::Day01>(1),\n\
+             \x20       Day::of::<day11::Day11>(11),\n    ]\n}\n"
+        );
+        let lib = fs::read_to_string(directory.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day11 {"));
+        let main = fs::read_to_string(directory.join("src/main.rs")).unwrap();
+        assert!(main.contains("run_main::<day11::Day11>(11)"));
+        assert!(directory.join("src/test_input").exists());
+
+        assert!(matches!(
+            create(&root, 1),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+        assert!(matches!(
+            create(&root, 11),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
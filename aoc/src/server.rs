@@ -0,0 +1,519 @@
+use std::{
+    io::{self, Read},
+    net::{SocketAddr, ToSocketAddrs},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use common::{ParseError, Part};
+use days::Day;
+use serde::Serialize;
+use tiny_http::{Header, Method, Request, Response};
+
+use crate::{
+    json::{milliseconds, Timings},
+    timing::time,
+};
+
+/// The answer to `POST /day/<day>/part/<part>`.
+#[derive(Debug, Serialize)]
+struct Answer {
+    day: u8,
+    part: u8,
+    answer: String,
+    /// `read_ms` is the time taken to receive the input.
+    timings: Timings,
+    /// What the solver pointed out about the input besides the answer.
+    warnings: Vec<String>,
+}
+
+/// What went wrong with a request, sent as `{"error": {"kind": ..., "message": ..., ...}}`.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Error {
+    NotFound {
+        message: String,
+    },
+    MethodNotAllowed {
+        message: String,
+    },
+    InvalidInput {
+        message: String,
+    },
+    TooLarge {
+        message: String,
+    },
+    /// The day's parser rejected the input. `line` and `column` count from 1.
+    Parse {
+        message: String,
+        line: Option<usize>,
+        column: Option<usize>,
+        expected: String,
+        found: String,
+    },
+    Panic {
+        message: String,
+    },
+    Timeout {
+        message: String,
+    },
+    /// As many solvers as allowed are still running.
+    Busy {
+        message: String,
+    },
+}
+
+impl Error {
+    fn status(&self) -> u16 {
+        match self {
+            Error::NotFound { .. } => 404,
+            Error::MethodNotAllowed { .. } => 405,
+            Error::InvalidInput { .. } => 400,
+            Error::TooLarge { .. } => 413,
+            Error::Parse { .. } => 422,
+            Error::Panic { .. } => 500,
+            Error::Timeout { .. } => 504,
+            Error::Busy { .. } => 503,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse {
+            message: err.to_string(),
+            line: err.line(),
+            column: err.column(),
+            expected: err.expected().to_string(),
+            found: err.found().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: Error,
+}
+
+#[derive(Serialize)]
+struct DayList {
+    days: Vec<u8>,
+}
+
+/// What the server allows a request to take.
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    /// How long a request may take before it is answered with a timeout error.
+    pub timeout: Duration,
+    /// The largest input accepted, in bytes.
+    pub max_input: usize,
+    /// How many solvers may run at once, counting those that outlived their request.
+    pub max_solvers: usize,
+}
+
+/// The solvers behind a small HTTP API:
+///
+/// - `GET /days` lists the days that can be solved.
+/// - `POST /day/<day>/part/<part>` solves a part on the request body.
+///
+/// Every request is handled on its own thread, and solved on another one. A request that takes
+/// longer than the time limit is answered with a timeout error; its solver cannot be stopped and
+/// finishes in the background, where it still counts against [`Limits::max_solvers`].
+pub struct Server {
+    http: tiny_http::Server,
+    days: Arc<Vec<Day>>,
+    limits: Limits,
+    solvers: Arc<AtomicUsize>,
+}
+
+impl Server {
+    pub fn bind(address: impl ToSocketAddrs, days: Vec<Day>, limits: Limits) -> io::Result<Server> {
+        Ok(Server {
+            http: tiny_http::Server::http(address).map_err(io::Error::other)?,
+            days: Arc::new(days),
+            limits,
+            solvers: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.http
+            .server_addr()
+            .to_ip()
+            .expect("the server listens on TCP")
+    }
+
+    /// Answers requests until the listening socket fails.
+    pub fn run(self) -> io::Result<()> {
+        loop {
+            let request = self.http.recv()?;
+            let context = Context {
+                days: Arc::clone(&self.days),
+                limits: self.limits,
+                solvers: Arc::clone(&self.solvers),
+            };
+
+            thread::spawn(move || handle(&context, request));
+        }
+    }
+}
+
+/// What a request is handled with.
+struct Context {
+    days: Arc<Vec<Day>>,
+    limits: Limits,
+    /// How many solvers are running.
+    solvers: Arc<AtomicUsize>,
+}
+
+/// One of the solvers [`Limits::max_solvers`] allows, given back when it is dropped.
+struct Slot(Arc<AtomicUsize>);
+
+impl Slot {
+    fn take(solvers: &Arc<AtomicUsize>, max: usize) -> Option<Slot> {
+        solvers
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |running| {
+                (running < max).then_some(running + 1)
+            })
+            .ok()
+            .map(|_| Slot(Arc::clone(solvers)))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn handle(context: &Context, mut request: Request) {
+    let max_input = context.limits.max_input;
+    let (body, duration) = time(|| {
+        if request
+            .body_length()
+            .is_some_and(|length| length > max_input)
+        {
+            return Ok(None);
+        }
+        // One byte more than allowed tells a body without a length that is too large.
+        let mut body = Vec::new();
+        request
+            .as_reader()
+            .take(max_input as u64 + 1)
+            .read_to_end(&mut body)?;
+        if body.len() > max_input {
+            return Ok(None);
+        }
+        String::from_utf8(body)
+            .map(Some)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    });
+
+    let (status, json) = match respond(context, request.method(), request.url(), body, duration) {
+        Ok(json) => (200, json),
+        Err(error) => (
+            error.status(),
+            serde_json::to_string(&ErrorBody { error }).unwrap(),
+        ),
+    };
+
+    let header = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(json + "\n")
+        .with_status_code(status)
+        .with_header(header);
+    if let Err(err) = request.respond(response) {
+        eprintln!("warning: could not send a response: {err}");
+    }
+}
+
+/// Answers a request, whose body is `None` if it is larger than [`Limits::max_input`].
+fn respond(
+    context: &Context,
+    method: &Method,
+    url: &str,
+    body: io::Result<Option<String>>,
+    read: Duration,
+) -> Result<String, Error> {
+    let days = &context.days;
+    let path = url.split_once('?').map_or(url, |(path, _)| path);
+    let segments: Vec<_> = path.trim_matches('/').split('/').collect();
+
+    let (day, part) = match segments.as_slice() {
+        ["days"] => {
+            expect_method(method, Method::Get)?;
+            let days = days.iter().map(|day| day.number).collect();
+            return Ok(serde_json::to_string(&DayList { days }).unwrap());
+        }
+        ["day", day, "part", part] => (*day, *part),
+        _ => {
+            return Err(Error::NotFound {
+                message: format!("there is nothing at {path}"),
+            })
+        }
+    };
+    expect_method(method, Method::Post)?;
+
+    let Some(index) = day
+        .parse()
+        .ok()
+        .and_then(|day: u8| days.iter().position(|known| known.number == day))
+    else {
+        return Err(Error::NotFound {
+            message: format!("day {day} is not solved yet"),
+        });
+    };
+    let part: Part = part.parse().map_err(|_| Error::NotFound {
+        message: format!("there is no part {part}"),
+    })?;
+    let input = body
+        .map_err(|err| Error::InvalidInput {
+            message: format!("could not read the input: {err}"),
+        })?
+        .ok_or_else(|| Error::TooLarge {
+            message: format!(
+                "the input is larger than {} bytes",
+                context.limits.max_input
+            ),
+        })?;
+
+    let max_solvers = context.limits.max_solvers;
+    let slot = Slot::take(&context.solvers, max_solvers).ok_or_else(|| Error::Busy {
+        message: format!("{max_solvers} solvers are already running, try again later"),
+    })?;
+
+    let (sender, receiver) = mpsc::channel();
+    let days = Arc::clone(days);
+    thread::spawn(move || {
+        let _slot = slot;
+        let day = &days[index];
+
+        let (parsed, parse) = time(|| days::catch_panic(|| day.solver.parse(&input)));
+        let result = parsed
+            .map_err(|message| Error::Panic { message })
+            .and_then(|parsed| parsed.map_err(Error::from))
+            .and_then(|parsed| {
+                let (solved, solve) = time(|| day.try_solve(part, &parsed));
+                let solved = solved.map_err(|message| Error::Panic { message })?;
+
+                Ok(Answer {
+                    day: day.number,
+                    part: part.into(),
+                    answer: solved.answer,
+                    timings: Timings {
+                        read_ms: Some(milliseconds(read)),
+                        parse_ms: Some(milliseconds(parse)),
+                        solve_ms: Some(milliseconds(solve)),
+                    },
+                    warnings: solved.warnings,
+                })
+            });
+
+        // Nobody is listening any more if the request timed out.
+        let _ = sender.send(result);
+    });
+
+    let timeout = context.limits.timeout;
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result.map(|answer| serde_json::to_string(&answer).unwrap()),
+        Err(mpsc::RecvTimeoutError::Timeout) => Err(Error::Timeout {
+            message: format!("the solver took longer than {}s", timeout.as_secs_f64()),
+        }),
+        Err(mpsc::RecvTimeoutError::Disconnected) => Err(Error::Panic {
+            message: "the solver stopped without an answer".to_string(),
+        }),
+    }
+}
+
+fn expect_method(method: &Method, expected: Method) -> Result<(), Error> {
+    if *method == expected {
+        Ok(())
+    } else {
+        Err(Error::MethodNotAllowed {
+            message: format!("use {expected} here instead of {method}"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        thread,
+        time::Duration,
+    };
+
+    use common::{ParseError, Solution, Solver};
+    use days::Day;
+    use serde_json::{json, Value};
+
+    use super::{Limits, Server};
+
+    /// Sleeps for as many milliseconds as the input says, in either part. Part two also keeps
+    /// track of how many of its calls run at the same time. Parsing `panic` panics.
+    struct Sleepy;
+
+    static SLEEPING: AtomicUsize = AtomicUsize::new(0);
+    static MOST_SLEEPING: AtomicUsize = AtomicUsize::new(0);
+
+    impl Solution for Sleepy {
+        type Input = u64;
+        type Answer = u64;
+
+        fn parse(input: &str) -> Result<u64, ParseError> {
+            if input.trim() == "panic" {
+                panic!("cannot sleep that long");
+            }
+            common::parse::number(input.trim())
+        }
+
+        fn part_one(millis: &u64) -> u64 {
+            thread::sleep(Duration::from_millis(*millis));
+            *millis
+        }
+
+        fn part_two(millis: &u64) -> u64 {
+            let sleeping = SLEEPING.fetch_add(1, Ordering::SeqCst) + 1;
+            MOST_SLEEPING.fetch_max(sleeping, Ordering::SeqCst);
+            let answer = Sleepy::part_one(millis);
+            SLEEPING.fetch_sub(1, Ordering::SeqCst);
+
+            answer
+        }
+    }
+
+    fn post(url: &str, input: &str) -> (u16, Value) {
+        let response = match ureq::post(url).send_string(input) {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => panic!("{err}"),
+        };
+
+        let status = response.status();
+        (
+            status,
+            serde_json::from_str(&response.into_string().unwrap()).unwrap(),
+        )
+    }
+
+    /// Serves every day and [`Sleepy`] as day 99, and returns the URL to reach it at.
+    fn serve(limits: Limits) -> String {
+        let mut days = days::all();
+        days.push(Day {
+            number: 99,
+            solver: Solver::of::<Sleepy>(),
+        });
+        let server = Server::bind("127.0.0.1:0", days, limits).unwrap();
+        let base_url = format!("http://{}", server.local_addr());
+        thread::spawn(move || server.run());
+
+        base_url
+    }
+
+    #[test]
+    fn serving_answers() {
+        let base_url = serve(Limits {
+            timeout: Duration::from_millis(500),
+            max_input: 1 << 20,
+            max_solvers: 8,
+        });
+
+        let (status, answer) = post(
+            &format!("{base_url}/day/6/part/2"),
+            "Time:      7  15   30\r\nDistance:  9  40  200\r\n",
+        );
+        assert_eq!(status, 200);
+        assert_eq!(answer["answer"], "71503");
+        assert_eq!((&answer["day"], &answer["part"]), (&json!(6), &json!(2)));
+        assert!(answer["timings"]["solve_ms"].is_f64());
+        assert_eq!(answer["warnings"], json!([]));
+
+        let (status, answer) = post(&format!("{base_url}/day/1/part/1"), "1abc2\nxyz\n");
+        assert_eq!(status, 200);
+        assert_eq!(answer["answer"], "12");
+        assert_eq!(
+            answer["warnings"],
+            json!(["line 2 has no digit and is left out of the sum"])
+        );
+
+        let (status, error) = post(
+            &format!("{base_url}/day/6/part/1"),
+            "Time: 7\nDistance: x\n",
+        );
+        assert_eq!(status, 422);
+        assert_eq!(
+            error["error"],
+            json!({
+                "kind": "parse",
+                "message": "line 2, column 11: expected a number, found \"x\"",
+                "line": 2,
+                "column": 11,
+                "expected": "a number",
+                "found": "x",
+            })
+        );
+
+        for (url, status, kind) in [
+            ("/day/26/part/1", 404, "not_found"),
+            ("/day/6/part/3", 404, "not_found"),
+            ("/day/99/part/1?slow", 504, "timeout"),
+        ] {
+            let (actual, error) = post(&format!("{base_url}{url}"), "1000");
+            assert_eq!(
+                (actual, &error["error"]["kind"]),
+                (status, &json!(kind)),
+                "{url}"
+            );
+        }
+
+        let response = ureq::get(&format!("{base_url}/days")).call().unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&response.into_string().unwrap()).unwrap()["days"],
+            json!([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 99])
+        );
+
+        // Four requests that take 300ms each are answered side by side. Rather than timing them,
+        // which is unreliable on a busy machine, the solver counts how many run at once.
+        let requests: Vec<_> = (0..4)
+            .map(|_| {
+                let url = format!("{base_url}/day/99/part/2");
+                thread::spawn(move || post(&url, "300"))
+            })
+            .collect();
+        for request in requests {
+            assert_eq!(request.join().unwrap().0, 200);
+        }
+        assert!(MOST_SLEEPING.load(Ordering::SeqCst) > 1);
+    }
+
+    #[test]
+    fn limiting_requests() {
+        let base_url = serve(Limits {
+            timeout: Duration::from_millis(100),
+            max_input: 16,
+            max_solvers: 1,
+        });
+
+        let (status, error) = post(&format!("{base_url}/day/99/part/1"), &"1".repeat(17));
+        assert_eq!(
+            (status, &error["error"]["kind"]),
+            (413, &json!("too_large"))
+        );
+
+        let (status, error) = post(&format!("{base_url}/day/99/part/1"), "panic");
+        assert_eq!((status, &error["error"]["kind"]), (500, &json!("panic")));
+        assert_eq!(
+            error["error"]["message"],
+            "the solver panicked: cannot sleep that long"
+        );
+
+        // The solver that timed out keeps its slot until it finishes a second later.
+        let (status, _) = post(&format!("{base_url}/day/99/part/1"), "1000");
+        assert_eq!(status, 504);
+        let (status, error) = post(&format!("{base_url}/day/99/part/1"), "0");
+        assert_eq!((status, &error["error"]["kind"]), (503, &json!("busy")));
+    }
+}
@@ -0,0 +1,301 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+    time::Duration,
+};
+
+use common::Part;
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, ClientError, Hint, Verdict};
+
+/// What is known about the answers of one part of one day.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Record {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    correct: Option<String>,
+    #[serde(default)]
+    wrong: Vec<WrongAnswer>,
+    /// Seconds since the Unix epoch before which the server will not take another answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    blocked_until: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct WrongAnswer {
+    answer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hint: Option<String>,
+}
+
+impl WrongAnswer {
+    fn hint(&self) -> Option<Hint> {
+        match self.hint.as_deref() {
+            Some("too high") => Some(Hint::TooHigh),
+            Some("too low") => Some(Hint::TooLow),
+            _ => None,
+        }
+    }
+
+    /// Whether this wrong answer rules out `answer` as well.
+    fn rules_out(&self, answer: &str) -> bool {
+        if self.answer == answer {
+            return true;
+        }
+
+        let (Ok(known), Ok(answer)) = (self.answer.parse::<i128>(), answer.parse::<i128>()) else {
+            return false;
+        };
+        match self.hint() {
+            Some(Hint::TooHigh) => answer >= known,
+            Some(Hint::TooLow) => answer <= known,
+            None => false,
+        }
+    }
+}
+
+/// The answers submitted so far, stored as JSON so that they survive between runs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Submissions {
+    #[serde(flatten)]
+    records: BTreeMap<String, Record>,
+}
+
+/// Why an answer was not submitted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part is already solved with this answer.
+    AlreadyCorrect(String),
+    /// The answer, or one this answer is on the wrong side of, was rejected before.
+    KnownWrong { answer: String, hint: Option<Hint> },
+    /// The server asked to wait this much longer before the next attempt.
+    Throttled(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect(answer) => {
+                write!(f, "the part is already solved with {answer}")
+            }
+            Refusal::KnownWrong { answer, hint } => {
+                write!(f, "{answer} was already submitted")?;
+                match hint {
+                    Some(hint) => write!(f, " and was {hint}"),
+                    None => write!(f, " and was wrong"),
+                }
+            }
+            Refusal::Throttled(wait) => write!(
+                f,
+                "the server asked to wait another {}s before the next attempt",
+                wait.as_secs()
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Client(ClientError),
+    Io(io::Error),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitting: {refusal}"),
+            SubmitError::Client(err) => err.fmt(f),
+            SubmitError::Io(err) => err.fmt(f),
+        }
+    }
+}
+
+impl From<ClientError> for SubmitError {
+    fn from(err: ClientError) -> Self {
+        SubmitError::Client(err)
+    }
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(err: io::Error) -> Self {
+        SubmitError::Io(err)
+    }
+}
+
+fn key(day: u8, part: Part) -> String {
+    format!("day {day:02} part {part}")
+}
+
+impl Submissions {
+    /// Reads the submissions stored at `path`, or starts afresh if there are none yet.
+    pub fn load(path: &Path) -> io::Result<Submissions> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {err}", path.display()),
+                )
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::from)?;
+        fs::write(path, json + "\n")
+    }
+
+    /// Checks whether submitting `answer` at `now` (in seconds since the Unix epoch) makes sense.
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> Result<(), Refusal> {
+        let Some(record) = self.records.get(&key(day, part)) else {
+            return Ok(());
+        };
+
+        if let Some(correct) = &record.correct {
+            return Err(Refusal::AlreadyCorrect(correct.clone()));
+        }
+        if let Some(wrong) = record.wrong.iter().find(|wrong| wrong.rules_out(answer)) {
+            return Err(Refusal::KnownWrong {
+                answer: wrong.answer.clone(),
+                hint: wrong.hint(),
+            });
+        }
+        match record.blocked_until {
+            Some(until) if until > now => Err(Refusal::Throttled(Duration::from_secs(until - now))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Remembers what the server made of `answer`.
+    pub fn record(&mut self, day: u8, part: Part, answer: &str, verdict: &Verdict, now: u64) {
+        let record = self.records.entry(key(day, part)).or_default();
+
+        match verdict {
+            Verdict::Correct => {
+                record.correct = Some(answer.to_string());
+                record.blocked_until = None;
+            }
+            Verdict::Wrong { hint, wait } => {
+                record.wrong.push(WrongAnswer {
+                    answer: answer.to_string(),
+                    hint: hint.map(|hint| hint.to_string()),
+                });
+                record.blocked_until = wait.map(|wait| now + wait.as_secs());
+            }
+            Verdict::RateLimited { wait } => record.blocked_until = Some(now + wait.as_secs()),
+            Verdict::WrongLevel => {}
+        }
+    }
+}
+
+/// Submits `answer` unless the submissions stored at `path` say it is pointless, and stores
+/// the verdict there.
+pub fn submit(
+    client: &Client,
+    path: &Path,
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Verdict, SubmitError> {
+    let mut submissions = Submissions::load(path)?;
+    submissions
+        .check(day, part, answer, now)
+        .map_err(SubmitError::Refused)?;
+
+    let verdict = client.submit(day, part, answer)?;
+
+    submissions.record(day, part, answer, &verdict, now);
+    submissions.save(path)?;
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, process, time::Duration};
+
+    use common::Part;
+
+    use super::{submit, Refusal, SubmitError};
+    use crate::client::{stub, Client, Hint, Verdict};
+
+    const TOO_HIGH: &str = "<html><body><main><article><p>That's not the right answer; \
+        your answer is too high.  If you're stuck, make sure you're using the full input data. \
+        Please wait one minute before trying again. \
+        <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article></main></body></html>";
+    const TOO_RECENTLY: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 1m 23s left to wait. \
+        <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>";
+    const RIGHT: &str = "<article><p>That's the right answer!  You are \
+        <span class=\"day-success\">one gold star</span> closer to restoring snow operations. \
+        </p></article>";
+
+    #[test]
+    fn submitting_answers() {
+        let (base_url, server) =
+            stub::serve(vec![(200, TOO_HIGH), (200, TOO_RECENTLY), (200, RIGHT)]);
+        let client = Client::new(&base_url, "secret".to_string());
+        let path = env::temp_dir()
+            .join(format!("aoc-submit-{}", process::id()))
+            .join("submissions.json");
+
+        assert_eq!(
+            submit(&client, &path, 1, Part::Two, "300", 1000).unwrap(),
+            Verdict::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60)),
+            }
+        );
+
+        // None of these reach the stub.
+        assert!(matches!(
+            submit(&client, &path, 1, Part::Two, "200", 1030),
+            Err(SubmitError::Refused(Refusal::Throttled(wait))) if wait.as_secs() == 30
+        ));
+        for answer in ["300", "301"] {
+            assert!(matches!(
+                submit(&client, &path, 1, Part::Two, answer, 2000),
+                Err(SubmitError::Refused(Refusal::KnownWrong {
+                    hint: Some(Hint::TooHigh),
+                    ..
+                }))
+            ));
+        }
+
+        assert_eq!(
+            submit(&client, &path, 1, Part::Two, "200", 2000).unwrap(),
+            Verdict::RateLimited {
+                wait: Duration::from_secs(83),
+            }
+        );
+        assert_eq!(
+            submit(&client, &path, 1, Part::Two, "200", 2100).unwrap(),
+            Verdict::Correct
+        );
+        assert_eq!(
+            submit(&client, &path, 1, Part::Two, "200", 2200)
+                .unwrap_err()
+                .to_string(),
+            "not submitting: the part is already solved with 200"
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].request_line, "POST /2023/day/1/answer HTTP/1.1");
+        assert_eq!(requests[0].body, "level=2&answer=300");
+        assert!(requests[0]
+            .headers
+            .iter()
+            .any(|header| header.eq_ignore_ascii_case("cookie: session=secret")));
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,34 @@
+/// Prints `rows` below `header` with every column right-aligned to its widest cell.
+pub fn print(header: &[String], rows: &[Vec<String>]) {
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .chain([header[column].chars().count()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+
+    let print_row = |row: &[String]| {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:>width$}"))
+            .collect();
+        println!("{}", cells.join(" | "));
+    };
+
+    print_row(header);
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|&width| "-".repeat(width))
+            .collect::<Vec<_>>()
+            .join("-+-")
+    );
+    for row in rows {
+        print_row(row);
+    }
+}
@@ -0,0 +1,227 @@
+use std::time::{Duration, Instant};
+
+use common::{input::Source, Part};
+
+use days::Day;
+
+use crate::table;
+
+/// Wall times of every phase of a day, one sample per run.
+pub struct Timings {
+    pub day: u8,
+    pub read: Vec<Duration>,
+    pub parse: Vec<Duration>,
+    pub parts: Vec<(Part, Vec<Duration>)>,
+}
+
+impl Timings {
+    fn phases(&self) -> impl Iterator<Item = (String, &[Duration])> {
+        [
+            ("read".to_string(), self.read.as_slice()),
+            ("parse".to_string(), self.parse.as_slice()),
+        ]
+        .into_iter()
+        .chain(
+            self.parts
+                .iter()
+                .map(|(part, samples)| (format!("part {part}"), samples.as_slice())),
+        )
+    }
+}
+
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Runs every phase of `day` `runs` times. Stdin can only be read once, so its input is reused.
+pub fn measure(day: &Day, source: &Source, parts: &[Part], runs: usize) -> Result<Timings, String> {
+    let mut timings = Timings {
+        day: day.number,
+        read: Vec::new(),
+        parse: Vec::new(),
+        parts: parts.iter().map(|&part| (part, Vec::new())).collect(),
+    };
+
+    let mut input = None;
+    for _ in 0..runs {
+        if input.is_none() || *source != Source::Stdin {
+            let (read, duration) = time(|| source.read());
+            timings.read.push(duration);
+            input = Some(read.map_err(|err| err.to_string())?);
+        }
+        let input = input.as_deref().unwrap_or_default();
+
+        let (parsed, duration) = time(|| day.solver.parse(input));
+        timings.parse.push(duration);
+        let parsed = parsed.map_err(|err| err.report(source, input).to_string())?;
+
+        for (part, samples) in &mut timings.parts {
+            let (answer, duration) = time(|| day.try_solve(*part, &parsed));
+            answer.map_err(|err| format!("part {part}: {err}"))?;
+            samples.push(duration);
+        }
+    }
+
+    Ok(timings)
+}
+
+fn min(samples: &[Duration]) -> Duration {
+    samples.iter().copied().min().unwrap_or_default()
+}
+
+fn median(samples: &[Duration]) -> Duration {
+    let mut samples = samples.to_vec();
+    samples.sort_unstable();
+
+    match samples.len() {
+        0 => Duration::ZERO,
+        len if len % 2 == 0 => (samples[len / 2 - 1] + samples[len / 2]) / 2,
+        len => samples[len / 2],
+    }
+}
+
+pub fn format(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+
+    match nanos {
+        n if n < 1e3 => format!("{n:.0}ns"),
+        n if n < 1e6 => format!("{:.2}µs", n / 1e3),
+        n if n < 1e9 => format!("{:.2}ms", n / 1e6),
+        n => format!("{:.2}s", n / 1e9),
+    }
+}
+
+/// Prints min and median of every phase of every day, followed by the totals across all days.
+pub fn print(timings: &[Timings], runs: usize) {
+    println!("Timings over {runs} runs");
+
+    let header = ["Day", "Phase", "Min", "Median"].map(String::from);
+    table::print(&header, &rows(timings));
+}
+
+/// The rows of [`print`]: every phase of every day and its total, then the totals of every phase
+/// across all days if there is more than one.
+fn rows(timings: &[Timings]) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut totals: Vec<(String, Duration, Duration)> = Vec::new();
+
+    for day in timings {
+        let mut day_min = Duration::ZERO;
+        let mut day_median = Duration::ZERO;
+
+        for (phase, samples) in day.phases() {
+            let (min, median) = (min(samples), median(samples));
+            day_min += min;
+            day_median += median;
+
+            match totals.iter_mut().find(|(name, ..)| *name == phase) {
+                Some((_, total_min, total_median)) => {
+                    *total_min += min;
+                    *total_median += median;
+                }
+                None => totals.push((phase.clone(), min, median)),
+            }
+
+            rows.push(vec![
+                format!("{:02}", day.day),
+                phase,
+                format(min),
+                format(median),
+            ]);
+        }
+
+        rows.push(vec![
+            format!("{:02}", day.day),
+            "total".to_string(),
+            format(day_min),
+            format(day_median),
+        ]);
+    }
+
+    if timings.len() > 1 {
+        let all_min = totals.iter().map(|(_, min, _)| *min).sum();
+        let all_median = totals.iter().map(|(_, _, median)| *median).sum();
+
+        rows.extend(totals.into_iter().map(|(phase, min, median)| {
+            vec!["all".to_string(), phase, format(min), format(median)]
+        }));
+        rows.push(vec![
+            "all".to_string(),
+            "total".to_string(),
+            format(all_min),
+            format(all_median),
+        ]);
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use common::Part;
+
+    use super::{median, min, rows, Timings};
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn min_and_median() {
+        let odd = millis(&[5, 1, 3]);
+        assert_eq!(min(&odd), Duration::from_millis(1));
+        assert_eq!(median(&odd), Duration::from_millis(3));
+
+        let even = millis(&[8, 2, 4, 6]);
+        assert_eq!(min(&even), Duration::from_millis(2));
+        assert_eq!(median(&even), Duration::from_millis(5));
+
+        assert_eq!(median(&[]), Duration::ZERO);
+    }
+
+    #[test]
+    fn totals() {
+        let timings = [
+            Timings {
+                day: 1,
+                read: millis(&[1, 3]),
+                parse: millis(&[2, 2]),
+                parts: vec![(Part::One, millis(&[4, 6, 5]))],
+            },
+            Timings {
+                day: 2,
+                read: millis(&[1]),
+                parse: millis(&[10]),
+                parts: vec![(Part::One, millis(&[1])), (Part::Two, millis(&[7]))],
+            },
+        ];
+
+        let lines: Vec<String> = rows(&timings).iter().map(|row| row.join(" ")).collect();
+        assert_eq!(
+            lines,
+            [
+                "01 read 1.00ms 2.00ms",
+                "01 parse 2.00ms 2.00ms",
+                "01 part 1 4.00ms 5.00ms",
+                "01 total 7.00ms 9.00ms",
+                "02 read 1.00ms 1.00ms",
+                "02 parse 10.00ms 10.00ms",
+                "02 part 1 1.00ms 1.00ms",
+                "02 part 2 7.00ms 7.00ms",
+                "02 total 19.00ms 19.00ms",
+                "all read 2.00ms 3.00ms",
+                "all parse 12.00ms 12.00ms",
+                "all part 1 5.00ms 6.00ms",
+                "all part 2 7.00ms 7.00ms",
+                "all total 26.00ms 28.00ms",
+            ]
+        );
+
+        // A single day has no totals across days.
+        assert_eq!(rows(&timings[..1]).len(), 4);
+    }
+}
@@ -0,0 +1,52 @@
+use std::str::FromStr;
+
+use common::{ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Input {
+    lines: Vec<String>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.lines().map(String::from).collect();
+
+        Ok(Input { lines })
+    }
+}
+
+pub struct Day{{NN}};
+
+impl Solution for Day{{NN}} {
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part_one(input: &Input) -> usize {
+        input.lines.len()
+    }
+
+    fn part_two(_input: &Input) -> usize {
+        // Not solved yet.
+        0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::Solution;
+
+    use crate::Day{{NN}};
+
+    #[test]
+    fn example() {
+        let input = Day{{NN}}::parse(include_str!("test_input")).unwrap();
+
+        assert_eq!(Day{{NN}}::part_one(&input), 0);
+    }
+}
@@ -0,0 +1,5 @@
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::run_main::<day{{NN}}::Day{{NN}}>({{N}})
+}
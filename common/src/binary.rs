@@ -0,0 +1,43 @@
+use std::process::ExitCode;
+
+use crate::{diagnostics, input::Source, Part, Solution, Solver};
+
+/// Everything the binary of a day does: reads the input named by the first command line
+/// argument, parses it and prints the answers to both parts, and their warnings to stderr.
+pub fn run_main<S>(day: u8) -> ExitCode
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    if let Err(err) = diagnostics::init(None) {
+        eprintln!("error: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    let source = Source::from_args(day);
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let solver = Solver::of::<S>();
+    let parsed = match solver.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{}", err.report(&source, &input));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for part in [Part::One, Part::Two] {
+        let solved = solver.solve(part, &parsed);
+        println!("part {part}: {}", solved.answer);
+        for warning in solved.warnings {
+            eprintln!("warning: part {part}: {warning}");
+        }
+    }
+
+    ExitCode::SUCCESS
+}
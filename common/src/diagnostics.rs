@@ -0,0 +1,34 @@
+use std::{
+    env,
+    io::{self, IsTerminal},
+};
+
+use tracing_subscriber::EnvFilter;
+
+/// The environment variable the diagnostics filter is read from if none is given.
+pub const ENV: &str = "AOC_LOG";
+
+/// Prints the diagnostics of the solvers that `filter` selects to stderr.
+///
+/// The filter takes `tracing` directives like `debug` for everything or `day07=debug` for a single
+/// day; a module path like `day07::hand` narrows it down further. Without a filter, [`ENV`] is
+/// used, and without that nothing is printed.
+pub fn init(filter: Option<&str>) -> Result<(), String> {
+    let filter = match filter {
+        Some(filter) => filter.to_string(),
+        None => env::var(ENV).unwrap_or_default(),
+    };
+    let filter = if filter.trim().is_empty() {
+        EnvFilter::new("off")
+    } else {
+        EnvFilter::try_new(&filter).map_err(|err| format!("invalid filter {filter:?}: {err}"))?
+    };
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .try_init()
+        .map_err(|err| err.to_string())
+}
@@ -0,0 +1,203 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The `input` file next to the day's `main.rs`.
+    Default(u8),
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Interprets a command line argument: `-` means stdin, anything else is a path and no
+    /// argument at all means the default location of the day.
+    pub fn from_arg(day: u8, arg: Option<&str>) -> Source {
+        match arg {
+            None => Source::Default(day),
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(path.into()),
+        }
+    }
+
+    /// The source given as the first command line argument.
+    pub fn from_args(day: u8) -> Source {
+        let arg = std::env::args().nth(1);
+
+        Source::from_arg(day, arg.as_deref())
+    }
+
+    /// Reads the whole input as it is. [`Solver::parse`](crate::Solver::parse) normalizes it.
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            Source::Default(day) => fs::read_to_string(default_path(*day)),
+            Source::Path(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+
+        result.map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        })
+    }
+
+    /// Opens the input to be read bit by bit instead of all at once.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        let file = match self {
+            Source::Default(day) => fs::File::open(default_path(*day)),
+            Source::Path(path) => fs::File::open(path),
+            Source::Stdin => return Ok(Box::new(io::stdin().lock())),
+        };
+
+        file.map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+            .map_err(|error| InputError {
+                source: self.clone(),
+                error,
+            })
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Default(day) => write!(f, "{}", default_path(*day).display()),
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => f.write_str("stdin"),
+        }
+    }
+}
+
+/// Cleans up what editors and operating systems do to a file, so that every input looks alike:
+/// a leading BOM is dropped, lines end in `\n` without trailing whitespace and there are no blank
+/// lines at the end. Lines are never removed or merged, so positions in the result still match
+/// the original file.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+
+    let mut normalized = String::with_capacity(input.len());
+    for line in input.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+
+    let end = normalized.trim_end().len();
+    normalized.truncate(end);
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+/// The environment variable that overrides where the workspace is.
+pub const ROOT_ENV: &str = "AOC_ROOT";
+
+/// The root of the workspace all days live in: [`ROOT_ENV`] if it is set, otherwise the nearest
+/// directory from the current one upwards whose `Cargo.toml` has a `[workspace]` section.
+/// Outside of any workspace, it is the current directory.
+pub fn workspace_root() -> PathBuf {
+    if let Some(root) = env::var_os(ROOT_ENV) {
+        return root.into();
+    }
+
+    let current = env::current_dir().unwrap_or_default();
+    find_workspace(&current).unwrap_or(current)
+}
+
+fn find_workspace(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|directory| {
+            fs::read_to_string(directory.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+        })
+        .map(Path::to_path_buf)
+}
+
+/// The directory next to a day's `main.rs`, which holds its input and example files.
+pub fn day_directory(day: u8) -> PathBuf {
+    workspace_root().join(format!("day{day:02}")).join("src")
+}
+
+/// The conventional location of a day's input: `dayNN/src/input` in the workspace.
+pub fn default_path(day: u8) -> PathBuf {
+    day_directory(day).join("input")
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: Source,
+    error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not read input from {}: {}",
+            self.source, self.error
+        )?;
+
+        if let (Source::Default(_), io::ErrorKind::NotFound) = (&self.source, self.error.kind()) {
+            write!(
+                f,
+                "\nhint: save your puzzle input there, or pass a path (or `-` for stdin) instead"
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, process};
+
+    use super::{find_workspace, normalize};
+
+    #[test]
+    fn normalizing() {
+        assert_eq!(
+            normalize("\u{feff}Time: 7  \r\n\r\nDistance: 9\t\r\n\r\n  \r\n"),
+            "Time: 7\n\nDistance: 9\n"
+        );
+        assert_eq!(normalize("1\n2"), "1\n2\n");
+        assert_eq!(normalize("\r\n"), "");
+    }
+
+    #[test]
+    fn finding_the_workspace() {
+        let root = env::temp_dir().join(format!("aoc-workspace-{}", process::id()));
+        let day = root.join("day01/src");
+        fs::create_dir_all(&day).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"day01\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("day01/Cargo.toml"),
+            "[package]\nname = \"day01\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(find_workspace(&day), Some(root.clone()));
+        assert_eq!(find_workspace(&root), Some(root.clone()));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,10 @@
+mod binary;
+pub mod diagnostics;
+pub mod input;
+pub mod parse;
+pub mod query;
+mod solution;
+
+pub use binary::run_main;
+pub use parse::ParseError;
+pub use solution::{parse_input, Parsed, Part, Solution, Solved, Solver};
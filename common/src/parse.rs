@@ -0,0 +1,269 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// A place in the puzzle input that could not be parsed, together with what was expected there.
+///
+/// Parsers only know the slice they are working on, so a new error has no position yet. It
+/// remembers where the offending text lives in memory instead, which lets [`ParseError::locate`]
+/// work out line and column once the whole input is at hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    address: usize,
+    position: Option<(usize, usize)>,
+    expected: String,
+    found: String,
+}
+
+impl ParseError {
+    /// Creates an error pointing at `found`, which has to be a slice of the text being parsed.
+    pub fn new(found: &str, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            address: found.as_ptr() as usize,
+            position: None,
+            expected: expected.into(),
+            found: found.lines().next().unwrap_or_default().to_string(),
+        }
+    }
+
+    /// Sets line and column relative to `text`, if the offending slice is part of it.
+    pub fn locate(mut self, text: &str) -> ParseError {
+        let start = text.as_ptr() as usize;
+        if !(start..=start + text.len()).contains(&self.address) {
+            return self;
+        }
+
+        let before = &text[..self.address - start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        self.position = Some((
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        ));
+        self
+    }
+
+    /// The line of the error, starting at 1, once it has been located.
+    pub fn line(&self) -> Option<usize> {
+        self.position.map(|(line, _)| line)
+    }
+
+    /// The column of the error in characters, starting at 1, once it has been located.
+    pub fn column(&self) -> Option<usize> {
+        self.position.map(|(_, column)| column)
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+
+    fn found_description(&self) -> String {
+        if self.found.is_empty() {
+            "end of line".to_string()
+        } else {
+            format!("{:?}", self.found)
+        }
+    }
+
+    /// Renders the error with the offending line of `input` and a marker below the problem.
+    pub fn report<'a>(&'a self, source: impl Display + 'a, input: &'a str) -> impl Display + 'a {
+        Report {
+            error: self.clone().locate(input),
+            source,
+            input,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((line, column)) = self.position {
+            write!(f, "line {line}, column {column}: ")?;
+        }
+        write!(
+            f,
+            "expected {}, found {}",
+            self.expected,
+            self.found_description()
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+struct Report<'a, S> {
+    error: ParseError,
+    source: S,
+    input: &'a str,
+}
+
+impl<S: Display> Display for Report<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let error = &self.error;
+        let (line, column) = error.position.unwrap_or((1, 1));
+
+        let text = self.input.lines().nth(line - 1).unwrap_or_default();
+        let gutter = " ".repeat(line.to_string().len());
+
+        writeln!(
+            f,
+            "error: expected {}, found {}",
+            error.expected,
+            error.found_description()
+        )?;
+        writeln!(f, "{gutter}--> {}:{line}:{column}", self.source)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line} | {text}")?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(column - 1),
+            "^".repeat(error.found.chars().count().max(1))
+        )
+    }
+}
+
+/// Removes `prefix` from the start of `s`.
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix).ok_or_else(|| {
+        let end = s
+            .char_indices()
+            .nth(prefix.chars().count())
+            .map_or(s.len(), |(i, _)| i);
+
+        ParseError::new(&s[..end], format!("{prefix:?}"))
+    })
+}
+
+/// Removes `suffix` from the end of `s`.
+pub fn strip_suffix<'a>(s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+    s.strip_suffix(suffix).ok_or_else(|| {
+        let start = s
+            .char_indices()
+            .nth_back(suffix.chars().count().saturating_sub(1))
+            .map_or(0, |(i, _)| i);
+
+        ParseError::new(&s[start..], format!("{suffix:?}"))
+    })
+}
+
+/// Splits `s` around the first occurrence of `delimiter`.
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(&s[s.len()..], format!("{delimiter:?}")))
+}
+
+/// The lines of `s` that are not blank, without line endings or trailing whitespace.
+pub fn lines(s: &str) -> impl Iterator<Item = &str> {
+    s.lines().map(str::trim_end).filter(|line| !line.is_empty())
+}
+
+/// The blocks of lines in `s` that are separated by one or more blank lines, without the
+/// whitespace around them.
+pub fn paragraphs(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = s;
+
+    std::iter::from_fn(move || {
+        let mut start = None;
+        let mut end = 0;
+        let mut offset = 0;
+
+        for line in rest.split_inclusive('\n') {
+            if !line.trim().is_empty() {
+                start.get_or_insert(offset);
+                end = offset + line.trim_end().len();
+            } else if start.is_some() {
+                break;
+            }
+            offset += line.len();
+        }
+
+        let paragraph = &rest[start?..end];
+        rest = &rest[offset..];
+        Some(paragraph)
+    })
+}
+
+/// Parses all of `s` as a number.
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(s, "a number"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{lines, number, paragraphs, split_once, strip_prefix, ParseError};
+
+    #[test]
+    fn locating() {
+        let input = "Card 1: 1 2\nCard 2: 3 x4";
+        let line = input.lines().nth(1).unwrap();
+        let token = line.split_whitespace().last().unwrap();
+
+        let error = number::<u32>(token).unwrap_err();
+        assert_eq!((error.line(), error.column()), (None, None));
+        assert_eq!(error.to_string(), "expected a number, found \"x4\"");
+
+        let error = error.locate(line);
+        assert_eq!((error.line(), error.column()), (Some(1), Some(11)));
+
+        let error = error.locate(input);
+        assert_eq!((error.line(), error.column()), (Some(2), Some(11)));
+        assert_eq!(error.found(), "x4");
+
+        // Text that does not contain the error leaves it untouched.
+        let error = error.locate("unrelated");
+        assert_eq!((error.line(), error.column()), (Some(2), Some(11)));
+    }
+
+    #[test]
+    fn helpers() {
+        let line = "Game 7; 3 blue";
+
+        let error = strip_prefix(line, "Card ").unwrap_err().locate(line);
+        assert_eq!((error.column(), error.found()), (Some(1), "Game "));
+
+        let error = split_once(line, ":").unwrap_err().locate(line);
+        assert_eq!((error.column(), error.found()), (Some(15), ""));
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 15: expected \":\", found end of line"
+        );
+
+        assert_eq!(
+            ParseError::new(line, "a card")
+                .report("input", line)
+                .to_string(),
+            "error: expected a card, found \"Game 7; 3 blue\"\n \
+             --> input:1:1\n  \
+             |\n\
+             1 | Game 7; 3 blue\n  \
+             | ^^^^^^^^^^^^^^"
+        );
+    }
+
+    #[test]
+    fn blank_lines() {
+        let input = "\r\nseeds: 1 2\r\n\r\n \r\n\r\na-to-b map:  \r\n1 2 3\r\n\r\n";
+
+        assert_eq!(
+            paragraphs(input).collect::<Vec<_>>(),
+            ["seeds: 1 2", "a-to-b map:  \r\n1 2 3"]
+        );
+        assert_eq!(
+            lines(input).collect::<Vec<_>>(),
+            ["seeds: 1 2", "a-to-b map:", "1 2 3"]
+        );
+
+        // Paragraphs are slices of the input, so errors in them can be located.
+        let map = paragraphs(input).nth(1).unwrap();
+        let error = number::<u32>(map).unwrap_err().locate(input);
+        assert_eq!((error.line(), error.column()), (Some(6), Some(1)));
+
+        assert_eq!(paragraphs("\n \n").count(), 0);
+    }
+}
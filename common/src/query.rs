@@ -0,0 +1,25 @@
+//! Questions `aoc repl` asks about a parsed input, besides solving the parts.
+
+use std::str::FromStr;
+
+/// A query the shell understands, for `help` and tab completion.
+pub struct Command {
+    pub name: &'static str,
+    /// The arguments, like `<id>`.
+    pub arguments: &'static str,
+    pub help: &'static str,
+}
+
+/// The arguments of a command, checked to be exactly as many as its usage asks for.
+pub fn arguments<'a, const N: usize>(
+    arguments: &[&'a str],
+    usage: &str,
+) -> Result<[&'a str; N], String> {
+    arguments.try_into().map_err(|_| format!("usage: {usage}"))
+}
+
+pub fn number<T: FromStr>(argument: &str) -> Result<T, String> {
+    argument
+        .parse()
+        .map_err(|_| format!("{argument:?} is not a number"))
+}
@@ -0,0 +1,212 @@
+use std::{
+    any::Any,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{input::normalize, query::Command, ParseError};
+
+/// A day of the calendar, split into parsing the input and solving both parts on the result.
+pub trait Solution {
+    /// The parsed puzzle input both parts work on.
+    type Input;
+    type Answer: Display;
+
+    /// The commands `aoc repl` offers for this day, besides solving the parts.
+    const COMMANDS: &'static [Command] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Self::Answer;
+
+    fn part_two(input: &Self::Input) -> Self::Answer;
+
+    /// What `part` points out about the input besides its answer, like lines it had to leave out.
+    /// Front ends report these next to the answer, which stays just the number.
+    fn warnings(_input: &Self::Input, _part: Part) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Answers `command`, which is one of [`Solution::COMMANDS`].
+    fn query(_input: &Self::Input, command: &str, _arguments: &[&str]) -> Result<String, String> {
+        Err(format!("unknown command {command:?}"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> u8 {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(format!("there is no part {other:?}, only 1 and 2")),
+        }
+    }
+}
+
+/// The answer to a part, together with its [warnings](Solution::warnings).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub answer: String,
+    pub warnings: Vec<String>,
+}
+
+/// The parsed input of some day, with its type erased.
+pub struct Parsed(Box<dyn Any>);
+
+/// A [`Solution`] with its types erased, so that all days can be handled alike.
+#[derive(Clone, Copy)]
+pub struct Solver {
+    parse: fn(&str) -> Result<Parsed, ParseError>,
+    part_one: fn(&Parsed) -> String,
+    part_two: fn(&Parsed) -> String,
+    warnings: fn(&Parsed, Part) -> Vec<String>,
+    commands: &'static [Command],
+    query: fn(&Parsed, &str, &[&str]) -> Result<String, String>,
+}
+
+impl Solver {
+    pub fn of<S>() -> Solver
+    where
+        S: Solution,
+        S::Input: 'static,
+    {
+        Solver {
+            parse: |input| parse_input::<S>(input).map(|parsed| Parsed(Box::new(parsed))),
+            part_one: |parsed| S::part_one(downcast::<S>(parsed)).to_string(),
+            part_two: |parsed| S::part_two(downcast::<S>(parsed)).to_string(),
+            warnings: |parsed, part| S::warnings(downcast::<S>(parsed), part),
+            commands: S::COMMANDS,
+            query: |parsed, command, arguments| S::query(downcast::<S>(parsed), command, arguments),
+        }
+    }
+
+    /// Parses `input` after [normalizing](normalize) it, locating any error within it.
+    pub fn parse(&self, input: &str) -> Result<Parsed, ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Solves `part` on input that was parsed by this solver.
+    pub fn solve(&self, part: Part, parsed: &Parsed) -> Solved {
+        let answer = match part {
+            Part::One => (self.part_one)(parsed),
+            Part::Two => (self.part_two)(parsed),
+        };
+
+        Solved {
+            answer,
+            warnings: (self.warnings)(parsed, part),
+        }
+    }
+
+    /// The commands [`Solver::query`] answers.
+    pub fn commands(&self) -> &'static [Command] {
+        self.commands
+    }
+
+    /// Answers `command` about input that was parsed by this solver.
+    pub fn query(
+        &self,
+        parsed: &Parsed,
+        command: &str,
+        arguments: &[&str],
+    ) -> Result<String, String> {
+        (self.query)(parsed, command, arguments)
+    }
+}
+
+/// Parses `input` for `S` after [normalizing](normalize) it, locating any error within it. This
+/// is what [`Solver::parse`] does, without erasing the type of the result.
+pub fn parse_input<S: Solution>(input: &str) -> Result<S::Input, ParseError> {
+    let input = normalize(input);
+    S::parse(&input).map_err(|err| err.locate(&input))
+}
+
+fn downcast<S>(parsed: &Parsed) -> &S::Input
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    parsed
+        .0
+        .downcast_ref()
+        .expect("input was parsed by a different solver")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Part, Solution, Solver};
+    use crate::ParseError;
+
+    /// Answers with the input exactly as its parser saw it.
+    struct Echo;
+
+    impl Solution for Echo {
+        type Input = String;
+        type Answer = String;
+
+        fn parse(input: &str) -> Result<String, ParseError> {
+            match input.find('?') {
+                Some(at) => Err(ParseError::new(&input[at..=at], "anything but ?")),
+                None => Ok(input.to_string()),
+            }
+        }
+
+        fn part_one(input: &String) -> String {
+            input.clone()
+        }
+
+        fn part_two(input: &String) -> String {
+            input.clone()
+        }
+
+        fn warnings(input: &String, part: Part) -> Vec<String> {
+            match part {
+                Part::Two if input.is_empty() => vec!["there is nothing to echo".to_string()],
+                _ => Vec::new(),
+            }
+        }
+    }
+
+    #[test]
+    fn parsing_normalizes_the_input() {
+        let solver = Solver::of::<Echo>();
+        let parsed = solver.parse("\u{feff}a \r\nb\r\n\r\n").unwrap();
+        assert_eq!(solver.solve(Part::One, &parsed).answer, "a\nb\n");
+
+        let empty = solver.parse("\r\n").unwrap();
+        assert_eq!(solver.solve(Part::One, &empty).warnings, [] as [String; 0]);
+        assert_eq!(
+            solver.solve(Part::Two, &empty).warnings,
+            ["there is nothing to echo"]
+        );
+
+        let err = solver.parse("a\r\nb?\r\n").err().unwrap();
+        assert_eq!((err.line(), err.column()), (Some(2), Some(2)));
+    }
+}
@@ -0,0 +1,610 @@
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    io::{self, BufRead, Read},
+    sync::OnceLock,
+};
+
+use aho_corasick::AhoCorasick;
+use common::{parse, query::Command, ParseError, Part, Solution};
+use rayon::prelude::*;
+
+mod queries;
+
+/// Which tokens of a line count as digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Only ASCII digits, as in part one.
+    Digits,
+    /// ASCII digits and digits spelled out as words like `seven`, as in part two.
+    Spelled,
+}
+
+impl Mode {
+    /// The digits of this mode: spelled digits are English.
+    pub fn lexicon(self) -> &'static Lexicon {
+        static DIGITS: OnceLock<Lexicon> = OnceLock::new();
+        static SPELLED: OnceLock<Lexicon> = OnceLock::new();
+
+        match self {
+            Mode::Digits => DIGITS.get_or_init(|| Lexicon::from_table(HashMap::new())),
+            Mode::Spelled => SPELLED.get_or_init(Lexicon::english),
+        }
+    }
+}
+
+const ENGLISH: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const GERMAN: [&str; 10] = [
+    "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+const FRENCH: [&str; 10] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+
+/// The words that spell out digits, next to the ASCII digits which every lexicon knows.
+///
+/// Tokens are both matched and read from the same table, so every token that is found has a
+/// value.
+#[derive(Debug, Clone)]
+pub struct Lexicon {
+    /// The digit of every pattern of the tokenizer, by pattern ID.
+    digits: Vec<usize>,
+    tokenizer: AhoCorasick,
+}
+
+impl Lexicon {
+    /// The names of the built-in lexicons, for [`Lexicon::builtin`].
+    pub const BUILTIN: [&'static str; 3] = ["english", "german", "french"];
+
+    /// A lexicon of `words`, where the word at index `n` spells out `n`.
+    ///
+    /// There are at most ten words, none of them empty, and each of them is a different token
+    /// from the others and from the ASCII digits.
+    pub fn new<S: Into<String>>(words: impl IntoIterator<Item = S>) -> Result<Lexicon, String> {
+        let mut table = HashMap::new();
+        for (n, word) in words.into_iter().map(Into::into).enumerate() {
+            if n > 9 {
+                return Err("a lexicon spells out at most ten digits".to_string());
+            }
+            if word.is_empty() {
+                return Err("a lexicon cannot spell a digit as nothing".to_string());
+            }
+            if is_digit(&word) || table.contains_key(&word) {
+                return Err(format!("{word:?} is spelled out more than once"));
+            }
+            table.insert(word, n);
+        }
+
+        Ok(Lexicon::from_table(table))
+    }
+
+    /// A lexicon of the words in `table`, which are checked to be neither empty nor an ASCII
+    /// digit.
+    fn from_table(mut table: HashMap<String, usize>) -> Lexicon {
+        table.extend((0..10).map(|n| (n.to_string(), n)));
+
+        let (tokens, digits): (Vec<_>, Vec<_>) = table.into_iter().unzip();
+        let tokenizer = AhoCorasick::new(tokens).unwrap();
+
+        Lexicon { digits, tokenizer }
+    }
+
+    /// A lexicon of ten distinct words, like one of the built-in ones.
+    fn spelled(words: [&str; 10]) -> Lexicon {
+        Lexicon::from_table(
+            words
+                .iter()
+                .enumerate()
+                .map(|(n, word)| (word.to_string(), n))
+                .collect(),
+        )
+    }
+
+    pub fn english() -> Lexicon {
+        Lexicon::spelled(ENGLISH)
+    }
+
+    pub fn german() -> Lexicon {
+        Lexicon::spelled(GERMAN)
+    }
+
+    pub fn french() -> Lexicon {
+        Lexicon::spelled(FRENCH)
+    }
+
+    /// The built-in lexicon called `name`, one of [`Lexicon::BUILTIN`].
+    pub fn builtin(name: &str) -> Option<Lexicon> {
+        match name {
+            "english" => Some(Lexicon::english()),
+            "german" => Some(Lexicon::german()),
+            "french" => Some(Lexicon::french()),
+            _ => None,
+        }
+    }
+
+    /// Reads a lexicon with a line like `seven = 7` per word. Blank lines and lines starting with
+    /// `#` are skipped, and a digit may be spelled out in more than one way, but a word only
+    /// spells out a single digit.
+    pub fn parse(s: &str) -> Result<Lexicon, ParseError> {
+        let mut table = HashMap::new();
+        for line in parse::lines(s).filter(|line| !line.trim_start().starts_with('#')) {
+            let (word, digit) = parse::split_once(line, "=")?;
+            let (word, digit) = (word.trim(), digit.trim());
+            if word.is_empty() {
+                return Err(ParseError::new(word, "a word"));
+            }
+            if is_digit(word) || table.contains_key(word) {
+                return Err(ParseError::new(
+                    word,
+                    "a word that is not spelled out before",
+                ));
+            }
+            match parse::number(digit) {
+                Ok(n) if n <= 9 => table.insert(word.to_string(), n),
+                _ => return Err(ParseError::new(digit, "a digit")),
+            };
+        }
+
+        Ok(Lexicon::from_table(table))
+    }
+
+    /// Every token of `line`, including those that overlap, like both `one` and `eight` in
+    /// `"oneight"`. The line is scanned once, and tokens are sorted by where they start and then
+    /// by where they end.
+    pub fn tokens<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Token<'a>> + 'a {
+        let mut tokens: Vec<_> = self
+            .tokenizer
+            .find_overlapping_iter(line)
+            .map(|token| Token {
+                offset: token.start(),
+                digit: self.digits[token.pattern()],
+                text: &line[token.range()],
+            })
+            .collect();
+        tokens.sort_by_key(|token| (token.offset, token.end()));
+
+        tokens.into_iter()
+    }
+
+    /// The first and last token of `line`, which are the same if it has only one, or `None` if it
+    /// has none.
+    ///
+    /// Tokens are ordered by where they start. Of two tokens that start at the same place the
+    /// longer one counts, like `sechs` over `sech`.
+    pub fn first_and_last<'a>(&'a self, line: &'a str) -> Option<(Token<'a>, Token<'a>)> {
+        let mut tokens = self.tokens(line);
+        let token = tokens.next()?;
+
+        Some(tokens.fold((token, token), |(first, last), token| {
+            let earlier = (token.offset, Reverse(token.end()));
+            let first = if earlier < (first.offset, Reverse(first.end())) {
+                token
+            } else {
+                first
+            };
+            let later = (token.offset, token.end());
+            let last = if later > (last.offset, last.end()) {
+                token
+            } else {
+                last
+            };
+            (first, last)
+        }))
+    }
+
+    /// The calibration value of a line: its first and last digit, read as a two-digit number.
+    ///
+    /// Spelled out digits may overlap, so the last one of `"oneight"` is `eight`. A line without
+    /// a digit in this lexicon has no calibration value.
+    pub fn calibration_value(&self, line: &str) -> Option<usize> {
+        self.first_and_last(line)
+            .map(|(first, last)| first.digit * 10 + last.digit)
+    }
+
+    /// Adds the calibration value of `line`, the line numbered `number`, to `calibration`. Blank
+    /// lines are skipped.
+    fn calibrate_line(&self, calibration: &mut Calibration, number: usize, line: &str) {
+        let line = line.trim_end();
+        if line.is_empty() {
+            return;
+        }
+
+        match self.calibration_value(line) {
+            Some(value) => calibration.sum += value,
+            None => calibration.lines_without_digit.push(number),
+        }
+    }
+
+    /// Sums the calibration values of a document that is already in memory.
+    pub fn calibrate_document(&self, document: &str) -> Calibration {
+        let mut calibration = Calibration::default();
+        for (i, line) in document.lines().enumerate() {
+            self.calibrate_line(&mut calibration, i + 1, line);
+        }
+
+        calibration
+    }
+
+    /// Sums the calibration values of a document one line at a time, so that only a single line
+    /// is ever held in memory.
+    pub fn calibrate(&self, mut reader: impl BufRead) -> io::Result<Calibration> {
+        let mut calibration = Calibration::default();
+        let mut line = String::new();
+        let mut number = 0;
+        while reader.read_line(&mut line)? > 0 {
+            number += 1;
+            let text = match number {
+                1 => line.strip_prefix(BOM).unwrap_or(&line),
+                _ => &line,
+            };
+            self.calibrate_line(&mut calibration, number, text);
+            line.clear();
+        }
+
+        Ok(calibration)
+    }
+
+    /// Like [`Lexicon::calibrate`], but the document is split into chunks of about `chunk_size`
+    /// bytes that end at a line break, and as many chunks as there are threads are summed in
+    /// parallel at a time. The result is the same as that of `calibrate`.
+    pub fn calibrate_in_parallel(
+        &self,
+        mut reader: impl BufRead,
+        chunk_size: usize,
+    ) -> io::Result<Calibration> {
+        let threads = rayon::current_num_threads();
+        let mut calibration = Calibration::default();
+        let mut lines = 0;
+        loop {
+            let mut chunks = Vec::with_capacity(threads);
+            while chunks.len() < threads {
+                match read_chunk(&mut reader, chunk_size)? {
+                    Some(chunk) => chunks.push(chunk),
+                    None => break,
+                }
+            }
+            let last_batch = chunks.len() < threads;
+            if lines == 0 {
+                if let Some(chunk) = chunks.first_mut() {
+                    if chunk.starts_with(BOM) {
+                        chunk.drain(..BOM.len_utf8());
+                    }
+                }
+            }
+
+            // Line numbers within a chunk count from 1 and are moved once the chunks before it
+            // are counted.
+            let sums: Vec<_> = chunks
+                .par_iter()
+                .map(|chunk| (self.calibrate_document(chunk), chunk.lines().count()))
+                .collect();
+            for (chunk, chunk_lines) in sums {
+                calibration.sum += chunk.sum;
+                calibration.lines_without_digit.extend(
+                    chunk
+                        .lines_without_digit
+                        .into_iter()
+                        .map(|number| lines + number),
+                );
+                lines += chunk_lines;
+            }
+
+            if last_batch {
+                return Ok(calibration);
+            }
+        }
+    }
+}
+
+const BOM: char = '\u{feff}';
+
+/// Whether `word` is one of the ASCII digits, which every lexicon already has as tokens.
+fn is_digit(word: &str) -> bool {
+    word.len() == 1 && word.as_bytes()[0].is_ascii_digit()
+}
+
+/// Reads about `size` bytes and then up to the next line break, or returns `None` at the end.
+fn read_chunk(reader: &mut impl BufRead, size: usize) -> io::Result<Option<String>> {
+    let mut chunk = Vec::with_capacity(size);
+    reader
+        .by_ref()
+        .take(size.max(1) as u64)
+        .read_to_end(&mut chunk)?;
+    if chunk.is_empty() {
+        return Ok(None);
+    }
+    if !chunk.ends_with(b"\n") {
+        reader.read_until(b'\n', &mut chunk)?;
+    }
+
+    String::from_utf8(chunk)
+        .map(Some)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// The sum of the calibration values of a document.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Calibration {
+    pub sum: usize,
+    /// The numbers of the lines without a digit, counting from 1. They are left out of `sum`.
+    pub lines_without_digit: Vec<usize>,
+}
+
+impl Calibration {
+    /// A warning for every line without a digit, as reported next to the sum.
+    pub fn warnings(&self) -> Vec<String> {
+        self.lines_without_digit
+            .iter()
+            .map(|line| format!("line {line} has no digit and is left out of the sum"))
+            .collect()
+    }
+}
+
+/// A digit found in a line, either as an ASCII digit or spelled out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    /// The byte offset of the token in its line.
+    pub offset: usize,
+    pub digit: usize,
+    /// What the token matched, like `"7"` or `"seven"`.
+    pub text: &'a str,
+}
+
+impl Token<'_> {
+    /// The byte offset just past the token.
+    pub fn end(&self) -> usize {
+        self.offset + self.text.len()
+    }
+}
+
+/// The calibration value of a line, see [`Lexicon::calibration_value`].
+pub fn calibration_value(line: &str, mode: Mode) -> Option<usize> {
+    mode.lexicon().calibration_value(line)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// The whole document, with its blank lines kept so that lines are numbered as in the input.
+    type Input = String;
+    type Answer = usize;
+
+    const COMMANDS: &'static [Command] = queries::COMMANDS;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(document: &String) -> usize {
+        Mode::Digits.lexicon().calibrate_document(document).sum
+    }
+
+    fn part_two(document: &String) -> usize {
+        Mode::Spelled.lexicon().calibrate_document(document).sum
+    }
+
+    fn warnings(document: &String, part: Part) -> Vec<String> {
+        let mode = match part {
+            Part::One => Mode::Digits,
+            Part::Two => Mode::Spelled,
+        };
+
+        mode.lexicon().calibrate_document(document).warnings()
+    }
+
+    fn query(document: &String, command: &str, arguments: &[&str]) -> Result<String, String> {
+        queries::query(document, command, arguments)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Instant;
+
+    use common::{Part, Solution};
+
+    use crate::{calibration_value, Calibration, Day01, Lexicon, Mode, ENGLISH};
+
+    /// Tries every word at every position of `line`.
+    fn calibration_value_by_brute_force(line: &str) -> usize {
+        let digits: Vec<_> = (0..line.len())
+            .filter_map(|i| line.get(i..))
+            .filter_map(|rest| {
+                (0..10).find(|&n| rest.starts_with(ENGLISH[n]) || rest.starts_with(&n.to_string()))
+            })
+            .collect();
+
+        digits[0] * 10 + digits[digits.len() - 1]
+    }
+
+    #[test]
+    fn digits() {
+        assert_eq!(calibration_value("0", Mode::Digits), Some(0));
+        assert_eq!(calibration_value("a0b5c", Mode::Digits), Some(5));
+        assert_eq!(calibration_value("90", Mode::Digits), Some(90));
+        assert_eq!(calibration_value("one2three", Mode::Digits), Some(22));
+        assert_eq!(calibration_value("one2three", Mode::Spelled), Some(13));
+
+        assert_eq!(calibration_value("abc", Mode::Digits), None);
+
+        let example = Day01::parse(include_str!("test_input")).unwrap();
+        assert_eq!(Day01::part_one(&example), 142);
+        assert!(Day01::warnings(&example, Part::One).is_empty());
+
+        // The example of part two has a line with only spelled out digits.
+        let example = Day01::parse(include_str!("test_input2")).unwrap();
+        assert_eq!(Day01::part_one(&example), 209);
+        assert_eq!(
+            Day01::warnings(&example, Part::One),
+            ["line 2 has no digit and is left out of the sum"]
+        );
+        assert_eq!(Day01::part_two(&example), 281);
+        assert!(Day01::warnings(&example, Part::Two).is_empty());
+
+        let document = "1\n\nx\ny\n2z\nw".to_string();
+        assert_eq!(Day01::part_one(&document), 33);
+        assert_eq!(
+            Mode::Digits
+                .lexicon()
+                .calibrate_document(&document)
+                .lines_without_digit,
+            [3, 4, 6]
+        );
+    }
+
+    #[test]
+    fn lexicons() {
+        let english = Lexicon::english();
+        assert_eq!(english.calibration_value("zero5"), Some(5));
+        assert_eq!(english.calibration_value("xtwone3four0"), Some(20));
+        assert_eq!(english.calibration_value("oneight"), Some(18));
+        for line in [
+            "eightwo",
+            "xtwonefïve3sevenine",
+            "nineight9",
+            "é1ü",
+            "twone",
+        ] {
+            assert_eq!(
+                english.calibration_value(line),
+                Some(calibration_value_by_brute_force(line)),
+                "{line}"
+            );
+        }
+
+        assert_eq!(
+            Lexicon::german().calibration_value("fünfsiebenull"),
+            Some(50)
+        );
+        assert_eq!(
+            Lexicon::french().calibration_value("a3zéroseptneuf"),
+            Some(39)
+        );
+        assert_eq!(Lexicon::french().calibration_value("sixsept"), Some(67));
+
+        let dutch = Lexicon::parse("# Dutch\nnul = 0\n\neen = 1\ntwee = 2\n").unwrap();
+        assert_eq!(dutch.calibration_value("tweeeen"), Some(21));
+        assert_eq!(dutch.calibration_value("one7"), Some(77));
+
+        let file = "een = 1\ntien = 10\n";
+        let err = Lexicon::parse(file).unwrap_err().locate(file);
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 8: expected a digit, found \"10\""
+        );
+        let file = "een = 1\ntwee = 2\neen = 3\n";
+        let err = Lexicon::parse(file).unwrap_err().locate(file);
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected a word that is not spelled out before, found \"een\""
+        );
+        assert!(Lexicon::parse("7 = 3\n").is_err());
+
+        assert!(Lexicon::new(ENGLISH).is_ok());
+        assert!(Lexicon::new(["nul", "een", "een"]).is_err());
+        assert!(Lexicon::new(["nul", ""]).is_err());
+        assert!(Lexicon::new(["0"]).is_err());
+        assert!(Lexicon::new(ENGLISH.into_iter().chain(["ten"])).is_err());
+    }
+
+    #[test]
+    fn tokens() {
+        let english = Lexicon::english();
+        let tokens = |line| {
+            english
+                .tokens(line)
+                .map(|token| (token.offset, token.digit, token.text))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            tokens("oneight2"),
+            [(0, 1, "one"), (2, 8, "eight"), (7, 2, "2")]
+        );
+        assert_eq!(
+            tokens("xtwone3"),
+            [(1, 2, "two"), (3, 1, "one"), (6, 3, "3")]
+        );
+        assert_eq!(tokens("abc"), []);
+
+        let sech = Lexicon::parse("sech = 6\nsechs = 6\n").unwrap();
+        let tokens: Vec<_> = sech.tokens("sechs").map(|token| token.text).collect();
+        assert_eq!(tokens, ["sech", "sechs"]);
+    }
+
+    /// Compares the single pass with scanning every suffix from the end, which is what finding
+    /// the last token used to do. Run with
+    /// `cargo test -p day01 --release -- --ignored --nocapture long_lines`.
+    #[test]
+    #[ignore = "slow in a debug build, run it with --release to compare the timings"]
+    fn long_lines() {
+        let line = format!(
+            "1{}oneightwo{}",
+            "abcdefghkl".repeat(3000),
+            "pqrst".repeat(2000)
+        );
+        let document = vec![line.as_str(); 50];
+        let lexicon = Lexicon::english();
+
+        let start = Instant::now();
+        let single_pass: usize = document
+            .iter()
+            .map(|line| lexicon.calibration_value(line).unwrap())
+            .sum();
+        let single_pass_time = start.elapsed();
+
+        let start = Instant::now();
+        let suffix_scan: usize = document
+            .iter()
+            .map(|line| {
+                let (first, _) = lexicon.first_and_last(line).unwrap();
+                let last = line
+                    .char_indices()
+                    .rev()
+                    .find_map(|(i, _)| lexicon.tokens(&line[i..]).next())
+                    .unwrap();
+                first.digit * 10 + last.digit
+            })
+            .sum();
+        let suffix_scan_time = start.elapsed();
+
+        println!("single pass: {single_pass_time:?}, suffix scan: {suffix_scan_time:?}");
+        assert_eq!(single_pass, 50 * 12);
+        assert_eq!(suffix_scan, single_pass);
+        assert!(single_pass_time < suffix_scan_time);
+    }
+
+    #[test]
+    fn streaming() {
+        let example = include_str!("test_input2");
+        let lexicon = Mode::Spelled.lexicon();
+        let expected = Day01::part_two(&Day01::parse(example).unwrap());
+        assert_eq!(lexicon.calibrate(example.as_bytes()).unwrap().sum, expected);
+
+        let document = "\u{feff}1abc2\r\n\nxyz\nthree\n\nnone\n7\nseven8\n";
+        let digits = Calibration {
+            sum: 12 + 77 + 88,
+            lines_without_digit: vec![3, 4, 6],
+        };
+        assert_eq!(
+            Mode::Digits
+                .lexicon()
+                .calibrate(document.as_bytes())
+                .unwrap(),
+            digits
+        );
+        for chunk_size in [0, 1, 2, 5, 8, 100] {
+            for lexicon in [Mode::Digits.lexicon(), lexicon] {
+                assert_eq!(
+                    lexicon
+                        .calibrate_in_parallel(document.as_bytes(), chunk_size)
+                        .unwrap(),
+                    lexicon.calibrate(document.as_bytes()).unwrap(),
+                    "chunks of {chunk_size} bytes"
+                );
+            }
+        }
+    }
+}
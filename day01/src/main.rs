@@ -1,46 +1,194 @@
-use regex::Regex;
-
-fn main() {
-    let number_regex =
-        Regex::new("zero|one|two|three|four|five|six|seven|eight|nine|0|1|2|3|4|5|6|7|8|9")
-            .unwrap();
-    let input = include_str!("input");
-    let sum: usize = input
-        .lines()
-        .map(|line| {
-            let mut matches = number_regex.find_iter(line);
-
-            let first_number = matches.next().unwrap().as_str();
-
-            let mut last_match = None;
-            for i in 1..=line.len() {
-                if let Some(offset) = number_regex.find_at(line, line.len() - i) {
-                    last_match = Some(offset.as_str());
-                    // last_match = Some(line.len() - i);
-                    break;
+use std::{env, fs, process::ExitCode};
+
+use common::{diagnostics, input::Source};
+use day01::{Calibration, Lexicon, Mode, Token};
+
+/// How much of the document `--parallel` hands to a thread at a time.
+const CHUNK_SIZE: usize = 1 << 20;
+
+const USAGE: &str =
+    "usage: day01 [--digits | --spelled] [--lexicon <english|german|french|path>] [--explain | --stream | --parallel] [input]";
+
+fn main() -> ExitCode {
+    if let Err(err) = diagnostics::init(None) {
+        eprintln!("error: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    // `--digits` only answers part one, `--spelled` only part two.
+    let (mut part_one, mut part_two, mut lexicon, mut path) = (true, true, None, None);
+    let (mut explain, mut stream, mut parallel) = (false, false, false);
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--digits" => part_two = false,
+            "--spelled" => part_one = false,
+            "--explain" => explain = true,
+            "--stream" => stream = true,
+            "--parallel" => parallel = true,
+            "--lexicon" if lexicon.is_none() => match args.next() {
+                Some(name) => lexicon = Some(name),
+                None => {
+                    eprintln!("error: --lexicon needs a value\n{USAGE}");
+                    return ExitCode::FAILURE;
+                }
+            },
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ if path.is_none() && (arg == "-" || !arg.starts_with('-')) => path = Some(arg),
+            _ => {
+                eprintln!("{USAGE}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    if !part_one && !part_two {
+        eprintln!("error: --digits and --spelled cannot be combined\n{USAGE}");
+        return ExitCode::FAILURE;
+    }
+    if [explain, stream, parallel]
+        .into_iter()
+        .filter(|&flag| flag)
+        .count()
+        > 1
+    {
+        eprintln!("error: --explain, --stream and --parallel cannot be combined\n{USAGE}");
+        return ExitCode::FAILURE;
+    }
+    // The document is read once per part, which stdin cannot do.
+    if (stream || parallel) && part_one && part_two {
+        eprintln!(
+            "error: --stream and --parallel answer one part, add --digits or --spelled\n{USAGE}"
+        );
+        return ExitCode::FAILURE;
+    }
+    if !part_two && lexicon.is_some() {
+        eprintln!("error: --lexicon only applies to spelled digits\n{USAGE}");
+        return ExitCode::FAILURE;
+    }
+
+    let lexicon = match lexicon.as_deref().map(load_lexicon) {
+        None => Lexicon::english(),
+        Some(Ok(lexicon)) => lexicon,
+        Some(Err(err)) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let source = Source::from_arg(1, path.as_deref());
+    let input = if stream || parallel {
+        None
+    } else {
+        match source.read() {
+            Ok(input) => Some(input),
+            Err(err) => {
+                eprintln!("error: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+    let calibrate = |lexicon: &Lexicon| -> Result<Calibration, String> {
+        let result = match &input {
+            Some(input) => lexicon.calibrate(input.as_bytes()),
+            None => {
+                let reader = source.open().map_err(|err| format!("error: {err}"))?;
+                if parallel {
+                    lexicon.calibrate_in_parallel(reader, CHUNK_SIZE)
+                } else {
+                    lexicon.calibrate(reader)
                 }
             }
-            let last_number = last_match.unwrap();
+        };
+        result.map_err(|err| format!("error: could not read {source}: {err}"))
+    };
+
+    // Part one only counts ASCII digits.
+    let digits = Mode::Digits.lexicon();
+    if let (true, Some(input)) = (explain, &input) {
+        print!(
+            "{}",
+            explanation(if part_two { &lexicon } else { digits }, input)
+        );
+        return ExitCode::SUCCESS;
+    }
+
+    let parts = [(part_one, 1, digits), (part_two, 2, &lexicon)];
+    for (_, part, lexicon) in parts.into_iter().filter(|(asked, _, _)| *asked) {
+        let calibration = match calibrate(lexicon) {
+            Ok(calibration) => calibration,
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+        if part_one && part_two {
+            println!("part {part}: {}", calibration.sum);
+        } else {
+            println!("{}", calibration.sum);
+        }
+        for warning in calibration.warnings() {
+            eprintln!("warning: part {part}: {warning}");
+        }
+    }
+
+    ExitCode::SUCCESS
+}
 
-            parse_digit_or_number_string(first_number) * 10
-                + parse_digit_or_number_string(last_number)
-        })
-        .sum();
+/// A built-in lexicon by name, or else one read from the file at `name`.
+fn load_lexicon(name: &str) -> Result<Lexicon, String> {
+    if let Some(lexicon) = Lexicon::builtin(name) {
+        return Ok(lexicon);
+    }
 
-    println!("{sum}");
+    let text = fs::read_to_string(name).map_err(|err| {
+        format!(
+            "error: {name} is neither one of {} nor a readable file: {err}",
+            Lexicon::BUILTIN.join(", ")
+        )
+    })?;
+    Lexicon::parse(&text).map_err(|err| err.report(name, &text).to_string())
 }
 
-fn parse_digit_or_number_string(first_number: &str) -> usize {
-    match first_number {
-        "1" | "one" => 1,
-        "2" | "two" => 2,
-        "3" | "three" => 3,
-        "4" | "four" => 4,
-        "5" | "five" => 5,
-        "6" | "six" => 6,
-        "7" | "seven" => 7,
-        "8" | "eight" => 8,
-        "9" | "nine" => 9,
-        unexpected => todo!("{unexpected} not handled yet"),
+/// Every line with its first and last token marked beneath it, followed by its calibration value.
+fn explanation(lexicon: &Lexicon, document: &str) -> String {
+    let mut explanation = String::new();
+    let mut sum = 0;
+    let document = document.strip_prefix('\u{feff}').unwrap_or(document);
+    for (n, line) in document.lines().enumerate() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            continue;
+        }
+        let prefix = format!("{}: ", n + 1);
+        explanation += &format!("{prefix}{line}\n");
+
+        let indent = prefix.len();
+        let Some((first, last)) = lexicon.first_and_last(line) else {
+            explanation += &format!("{:indent$}no digit\n", "");
+            continue;
+        };
+        explanation += &marker(line, indent, first, "first");
+        explanation += &marker(line, indent, last, "last");
+
+        let value = first.digit * 10 + last.digit;
+        explanation += &format!("{:indent$}calibration value: {value}\n", "");
+        sum += value;
     }
+
+    explanation + &format!("sum: {sum}\n")
+}
+
+/// A line of carets under `token` in `line`, which is indented by `indent`.
+fn marker(line: &str, indent: usize, token: Token, which: &str) -> String {
+    let column = indent + line[..token.offset].chars().count();
+    let carets = "^".repeat(token.text.chars().count());
+
+    format!(
+        "{:column$}{carets} {which}: {:?} is {}\n",
+        "", token.text, token.digit
+    )
 }
@@ -0,0 +1,30 @@
+use common::query::{arguments, number, Command};
+
+use crate::{calibration_value, Mode};
+
+pub(crate) const COMMANDS: &[Command] = &[Command {
+    name: "line",
+    arguments: "<n>",
+    help: "show line n, counting from 1, and its calibration values",
+}];
+
+pub(crate) fn query(document: &str, _: &str, args: &[&str]) -> Result<String, String> {
+    let [n] = arguments(args, "line <n>")?;
+    let n: usize = number(n)?;
+    let line = n
+        .checked_sub(1)
+        .and_then(|i| document.lines().nth(i))
+        .ok_or_else(|| format!("there is no line {n}"))?;
+
+    let value = |mode| {
+        calibration_value(line, mode).map_or("none, it has no digit".to_string(), |value| {
+            value.to_string()
+        })
+    };
+
+    Ok(format!(
+        "{line}\ncalibration value: {}\nwith spelled digits: {}",
+        value(Mode::Digits),
+        value(Mode::Spelled)
+    ))
+}
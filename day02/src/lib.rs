@@ -0,0 +1,216 @@
+use std::{cmp, iter::Sum, ops::Add, str::FromStr};
+
+use common::{
+    parse::{self, ParseError},
+    query::Command,
+    Solution,
+};
+
+mod queries;
+
+/// A game of drawing cubes from a bag, like `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub id: u32,
+    /// The cubes shown in each round, one entry per `;`-separated group.
+    pub runs: Vec<Run>,
+}
+
+impl Game {
+    /// Whether every round could have been drawn from `bag`.
+    pub fn is_possible_with(&self, bag: &Bag) -> bool {
+        self.runs.iter().all(|run| run.is_possible_with(bag))
+    }
+
+    /// The fewest cubes of each colour that make the game possible.
+    pub fn minimal_bag(&self) -> Bag {
+        self.runs.iter().fold(Bag::default(), |bag, run| Bag {
+            red: cmp::max(bag.red, run.red),
+            green: cmp::max(bag.green, run.green),
+            blue: cmp::max(bag.blue, run.blue),
+        })
+    }
+}
+
+/// The cubes shown in one round of a game.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub blue: u32,
+    pub red: u32,
+    pub green: u32,
+}
+
+/// The cubes in a bag.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Bag {
+    pub blue: u32,
+    pub red: u32,
+    pub green: u32,
+}
+
+impl Bag {
+    /// The numbers of cubes of each colour multiplied together.
+    pub fn power(&self) -> u32 {
+        self.blue * self.red * self.green
+    }
+}
+
+impl Add for Run {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Run {
+            blue: self.blue + rhs.blue,
+            red: self.red + rhs.red,
+            green: self.green + rhs.green,
+        }
+    }
+}
+
+impl Sum for Run {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|a, b| a + b).unwrap_or_default()
+    }
+}
+
+impl Run {
+    fn only_blue(count: u32) -> Self {
+        Run {
+            blue: count,
+            red: 0,
+            green: 0,
+        }
+    }
+
+    fn only_red(count: u32) -> Self {
+        Run {
+            red: count,
+            blue: 0,
+            green: 0,
+        }
+    }
+
+    fn only_green(count: u32) -> Self {
+        Run {
+            green: count,
+            blue: 0,
+            red: 0,
+        }
+    }
+
+    /// Whether the bag holds at least the cubes of this round.
+    pub fn is_possible_with(&self, bag: &Bag) -> bool {
+        let &Run { red, green, blue } = self;
+
+        red <= bag.red && green <= bag.green && blue <= bag.blue
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = parse::strip_prefix(s, "Game ")?;
+        let (id, rest) = parse::split_once(s, ":")?;
+
+        let runs = rest
+            .split(';')
+            .map(|run| {
+                run.split(',')
+                    .map(|drawing| {
+                        let drawing = drawing.trim();
+                        let (count, colour) = parse::split_once(drawing, " ")?;
+                        let count: u32 = parse::number(count)?;
+
+                        match colour {
+                            "green" => Ok(Run::only_green(count)),
+                            "red" => Ok(Run::only_red(count)),
+                            "blue" => Ok(Run::only_blue(count)),
+                            unknown => {
+                                Err(ParseError::new(unknown, "\"red\", \"green\" or \"blue\""))
+                            }
+                        }
+                    })
+                    .sum()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Game {
+            id: parse::number(id)?,
+            runs,
+        })
+    }
+}
+
+/// The bag of part one.
+pub const BAG: Bag = Bag {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Answer = u32;
+
+    const COMMANDS: &'static [Command] = queries::COMMANDS;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        parse::lines(input)
+            .map(|line| {
+                let game = line.parse::<Game>()?;
+                tracing::debug!(?game, "parsed game");
+                Ok(game)
+            })
+            .collect()
+    }
+
+    fn part_one(games: &Vec<Game>) -> u32 {
+        games
+            .iter()
+            .filter(|game| game.is_possible_with(&BAG))
+            .map(|game| game.id)
+            .sum()
+    }
+
+    fn part_two(games: &Vec<Game>) -> u32 {
+        games.iter().map(|game| game.minimal_bag().power()).sum()
+    }
+
+    fn query(games: &Vec<Game>, command: &str, arguments: &[&str]) -> Result<String, String> {
+        queries::query(games, command, arguments)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::Solver;
+
+    use crate::Day02;
+
+    #[test]
+    fn parse_errors() {
+        let error = |input| {
+            Solver::of::<Day02>()
+                .parse(input)
+                .err()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(
+            error("Game 1: 3 blue, 4 red\nGame 2: 1 purple\n"),
+            "line 2, column 11: expected \"red\", \"green\" or \"blue\", found \"purple\""
+        );
+        assert_eq!(
+            error("Game 1 3 blue\n"),
+            "line 1, column 14: expected \":\", found end of line"
+        );
+        assert_eq!(
+            error("Game x: 3 blue\n"),
+            "line 1, column 6: expected a number, found \"x\""
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use common::query::{arguments, number, Command};
+
+use crate::{Bag, Game, BAG};
+
+fn cubes(Bag { red, green, blue }: Bag) -> String {
+    format!("{red} red, {green} green, {blue} blue")
+}
+
+pub(crate) const COMMANDS: &[Command] = &[Command {
+    name: "game",
+    arguments: "<id>",
+    help: "show the rounds of a game and the smallest bag it needs",
+}];
+
+pub(crate) fn query(games: &[Game], _: &str, args: &[&str]) -> Result<String, String> {
+    let [id] = arguments(args, "game <id>")?;
+    let id: u32 = number(id)?;
+    let game = games
+        .iter()
+        .find(|game| game.id == id)
+        .ok_or_else(|| format!("there is no game {id}"))?;
+
+    let mut lines: Vec<_> = game
+        .runs
+        .iter()
+        .enumerate()
+        .map(|(i, run)| {
+            let bag = Bag {
+                red: run.red,
+                green: run.green,
+                blue: run.blue,
+            };
+            format!("round {}: {}", i + 1, cubes(bag))
+        })
+        .collect();
+    let minimal_bag = game.minimal_bag();
+    lines.push(format!(
+        "smallest bag: {}, power {}",
+        cubes(minimal_bag),
+        minimal_bag.power()
+    ));
+    lines.push(format!(
+        "possible with {}: {}",
+        cubes(BAG),
+        game.is_possible_with(&BAG)
+    ));
+
+    Ok(lines.join("\n"))
+}
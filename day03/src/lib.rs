@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use common::{parse, query::Command, ParseError, Solution};
+use itertools::Itertools;
+
+mod queries;
+
+type Symbols = HashMap<(usize, usize), (char, Vec<u32>)>;
+
+/// An engine schematic, reduced to what the puzzle asks about.
+pub struct Schematic {
+    /// Every symbol together with the numbers adjacent to it.
+    symbols: Symbols,
+    /// Every number that is adjacent to at least one symbol.
+    part_numbers: Vec<u32>,
+}
+
+impl Schematic {
+    /// Every number that is adjacent to at least one symbol.
+    pub fn part_numbers(&self) -> &[u32] {
+        &self.part_numbers
+    }
+
+    /// Every symbol at its `(x, y)` position, with the numbers adjacent to it.
+    pub fn symbols(&self) -> impl Iterator<Item = ((usize, usize), char, &[u32])> {
+        self.symbols
+            .iter()
+            .map(|(&position, (c, numbers))| (position, *c, numbers.as_slice()))
+    }
+
+    /// The product of the two numbers of every `*` that is adjacent to exactly two numbers.
+    pub fn gear_ratios(&self) -> impl Iterator<Item = u32> + '_ {
+        self.symbols()
+            .filter(|(_, c, numbers)| *c == '*' && numbers.len() == 2)
+            .map(|(_, _, numbers)| numbers.iter().product())
+    }
+}
+
+/// Finds the symbols and part numbers in a schematic. Anything but digits and `.` is a symbol.
+///
+/// Fails if a number does not fit in a `u32`.
+pub fn scan(input: &str) -> Result<Schematic, ParseError> {
+    let mut symbols = parse::lines(input)
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars().enumerate().filter_map(move |(x, c)| {
+                if c.is_ascii_digit() || c == '.' {
+                    None
+                } else {
+                    Some(((x, y), (c, Vec::new())))
+                }
+            })
+        })
+        .collect::<Symbols>();
+
+    let mut part_numbers = Vec::new();
+
+    for (y, line) in parse::lines(input).enumerate() {
+        let runs = line
+            .char_indices()
+            .enumerate()
+            .group_by(|(_, (_, c))| c.is_ascii_digit());
+        for run in runs
+            .into_iter()
+            .filter_map(|(is_digit, group)| is_digit.then_some(group))
+        {
+            let run = run.collect_vec();
+
+            let (begin, (start, _)) = run[0];
+            let (end, (last, _)) = run[run.len() - 1];
+
+            // Digits are ASCII, so the run ends one byte after its last digit.
+            let number: u32 = parse::number(&line[start..=last])?;
+
+            let before_begin = begin.saturating_sub(1);
+            let after_end = end + 1;
+            let mut is_part_number = false;
+            for cords in (before_begin..=after_end)
+                .flat_map(|x| [(x, y.wrapping_sub(1)), (x, y + 1)])
+                .chain([(before_begin, y), (after_end, y)])
+            {
+                if let Some((_, numbers)) = symbols.get_mut(&cords) {
+                    numbers.push(number);
+                    is_part_number = true;
+                }
+            }
+
+            if is_part_number {
+                part_numbers.push(number);
+            }
+        }
+    }
+
+    Ok(Schematic {
+        symbols,
+        part_numbers,
+    })
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+    type Answer = u32;
+
+    const COMMANDS: &'static [Command] = queries::COMMANDS;
+
+    fn parse(input: &str) -> Result<Schematic, ParseError> {
+        scan(input)
+    }
+
+    fn part_one(schematic: &Schematic) -> u32 {
+        schematic.part_numbers().iter().sum()
+    }
+
+    fn part_two(schematic: &Schematic) -> u32 {
+        schematic.gear_ratios().sum()
+    }
+
+    fn query(schematic: &Schematic, command: &str, arguments: &[&str]) -> Result<String, String> {
+        queries::query(schematic, command, arguments)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::scan;
+
+    #[test]
+    fn numbers_too_large() {
+        let input = "467..114..\n...*......\n..99999999999*\n";
+        let err = scan(input).err().unwrap().locate(input);
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 3: expected a number, found \"99999999999\""
+        );
+    }
+}
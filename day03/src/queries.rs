@@ -0,0 +1,50 @@
+use common::query::{arguments, number, Command};
+
+use crate::Schematic;
+
+pub(crate) const COMMANDS: &[Command] = &[
+    Command {
+        name: "symbol",
+        arguments: "<x> <y>",
+        help: "show the symbol at a position and the numbers next to it",
+    },
+    Command {
+        name: "gears",
+        arguments: "",
+        help: "list every gear with its ratio",
+    },
+];
+
+pub(crate) fn query(schematic: &Schematic, command: &str, args: &[&str]) -> Result<String, String> {
+    match command {
+        "symbol" => {
+            let [x, y] = arguments(args, "symbol <x> <y>")?;
+            let position = (number(x)?, number(y)?);
+            let (_, symbol, numbers) = schematic
+                .symbols()
+                .find(|(at, _, _)| *at == position)
+                .ok_or_else(|| format!("there is no symbol at {x},{y}"))?;
+
+            Ok(format!("{symbol} next to {numbers:?}"))
+        }
+        "gears" => {
+            arguments::<0>(args, "gears")?;
+            let mut gears: Vec<_> = schematic
+                .symbols()
+                .filter(|(_, c, numbers)| *c == '*' && numbers.len() == 2)
+                .map(|((x, y), _, numbers)| {
+                    let ratio: u32 = numbers.iter().product();
+                    ((y, x), format!("{x},{y}: {numbers:?}, ratio {ratio}"))
+                })
+                .collect();
+            gears.sort_unstable();
+
+            Ok(gears
+                .into_iter()
+                .map(|(_, line)| line)
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+        _ => unreachable!("{command} is not one of the commands"),
+    }
+}
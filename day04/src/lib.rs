@@ -0,0 +1,203 @@
+use std::{collections::HashSet, str::FromStr};
+
+use common::{
+    parse::{self, ParseError},
+    query::Command,
+    Solution,
+};
+
+mod queries;
+
+/// A scratchcard, like `Card 1: 41 48 83 | 83 86 6 31`.
+#[derive(Debug, Clone)]
+pub struct Card {
+    id: u32,
+    winning_numbers: HashSet<u32>,
+    numbers: HashSet<u32>,
+}
+
+impl Card {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// The numbers left of the `|`.
+    pub fn winning_numbers(&self) -> &HashSet<u32> {
+        &self.winning_numbers
+    }
+
+    /// The numbers right of the `|`.
+    pub fn numbers(&self) -> &HashSet<u32> {
+        &self.numbers
+    }
+
+    /// How many of the numbers are winning numbers.
+    pub fn matches(&self) -> usize {
+        self.winning_numbers.intersection(&self.numbers).count()
+    }
+
+    /// The points of part one: 1 for the first match, doubled for every further one.
+    pub fn worth(&self) -> u32 {
+        match self.matches() {
+            0 => 0,
+            matches => 1 << (matches - 1),
+        }
+    }
+}
+
+/// How many scratchcards there are in the end, when every card wins copies of the cards after it.
+pub fn total_cards(cards: &[Card]) -> u32 {
+    let mut game = Game {
+        cards: cards.iter().map(|card| (card.clone(), None)).collect(),
+    };
+
+    game.value()
+}
+
+struct Game {
+    cards: Vec<(Card, Option<u32>)>,
+}
+
+impl Game {
+    fn value(&mut self) -> u32 {
+        (1..=self.cards.len())
+            .map(|id| self.value_of_card(id))
+            .sum()
+    }
+
+    fn value_of_card(&mut self, id: usize) -> u32 {
+        let (card, value) = &mut self.cards[id - 1];
+        let matches = card.matches();
+        tracing::trace!(id, matches, "scored card");
+
+        if let Some(value) = value {
+            *value
+        } else {
+            let value = ((id + 1)..(id + 1 + matches))
+                .map(|id| self.value_of_card(id))
+                .sum::<u32>()
+                + 1;
+
+            self.cards[id - 1].1 = Some(value);
+            value
+        }
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = parse::strip_prefix(s, "Card")?.trim();
+        let (id, rest) = parse::split_once(s, ":")?;
+        let id = parse::number(id)?;
+
+        let (winning, numbers) = parse::split_once(rest, "|")?;
+
+        let [winning_numbers, numbers] = [winning, numbers].map(|list| {
+            list.split_whitespace()
+                .map(parse::number::<u32>)
+                .collect::<Result<_, _>>()
+        });
+        let (winning_numbers, numbers) = (winning_numbers?, numbers?);
+
+        let card = Card {
+            id,
+            winning_numbers,
+            numbers,
+        };
+        // The points of a card double with every match, which only fits for so many.
+        if card.matches() > u32::BITS as usize {
+            return Err(ParseError::new(
+                rest,
+                format!("at most {} matching numbers", u32::BITS),
+            ));
+        }
+
+        Ok(card)
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+    type Answer = u32;
+
+    const COMMANDS: &'static [Command] = queries::COMMANDS;
+
+    fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+        let lines: Vec<_> = parse::lines(input).collect();
+        let cards: Vec<Card> = lines
+            .iter()
+            .map(|line| line.parse())
+            .collect::<Result<_, _>>()?;
+
+        // Part two finds the cards by their position and never wins copies past the last one.
+        for (i, (card, line)) in cards.iter().zip(&lines).enumerate() {
+            if card.id as usize != i + 1 {
+                return Err(ParseError::new(line, format!("card {}", i + 1)));
+            }
+            let after = cards.len() - i - 1;
+            if card.matches() > after {
+                return Err(ParseError::new(
+                    line,
+                    format!("a card with at most {after} matches, one for every card after it"),
+                ));
+            }
+        }
+
+        Ok(cards)
+    }
+
+    fn part_one(cards: &Vec<Card>) -> u32 {
+        cards.iter().map(Card::worth).sum()
+    }
+
+    fn part_two(cards: &Vec<Card>) -> u32 {
+        total_cards(cards)
+    }
+
+    fn query(cards: &Vec<Card>, command: &str, arguments: &[&str]) -> Result<String, String> {
+        queries::query(cards, command, arguments)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::Solver;
+
+    use crate::Day04;
+
+    #[test]
+    fn parse_errors() {
+        let error = |input: &str| {
+            Solver::of::<Day04>()
+                .parse(input)
+                .err()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(
+            error("Card 1: 1 | 1\n"),
+            "line 1, column 1: expected a card with at most 0 matches, one for every card after it, found \"Card 1: 1 | 1\""
+        );
+        assert_eq!(
+            error("Card 1: 1 | 2\nCard 3: 4 | 5\n"),
+            "line 2, column 1: expected card 2, found \"Card 3: 4 | 5\""
+        );
+        assert_eq!(
+            error("Card 1: 1 2 | x\n"),
+            "line 1, column 15: expected a number, found \"x\""
+        );
+
+        // 33 matches would be worth 2^32 points, with enough cards after it to win copies of.
+        let numbers: Vec<_> = (1..=33).map(|n| n.to_string()).collect();
+        let mut input = format!("Card 1: {0} | {0}\n", numbers.join(" "));
+        for id in 2..=34 {
+            input += &format!("Card {id}: 1 | 2\n");
+        }
+        assert!(error(&input).starts_with("line 1, column 8: expected at most 32 matching numbers"));
+    }
+}
@@ -0,0 +1,39 @@
+use std::collections::HashSet;
+
+use common::query::{arguments, number, Command};
+
+use crate::Card;
+
+fn sorted(numbers: &HashSet<u32>) -> String {
+    let mut numbers: Vec<_> = numbers.iter().collect();
+    numbers.sort_unstable();
+
+    numbers
+        .into_iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub(crate) const COMMANDS: &[Command] = &[Command {
+    name: "card",
+    arguments: "<id>",
+    help: "show a card, its winning numbers and its points",
+}];
+
+pub(crate) fn query(cards: &[Card], _: &str, args: &[&str]) -> Result<String, String> {
+    let [id] = arguments(args, "card <id>")?;
+    let id: u32 = number(id)?;
+    let card = cards
+        .iter()
+        .find(|card| card.id() == id)
+        .ok_or_else(|| format!("there is no card {id}"))?;
+
+    Ok(format!(
+        "Card {id}\nwinning numbers: {}\nnumbers: {}\nmatches: {}, worth {} points",
+        sorted(card.winning_numbers()),
+        sorted(card.numbers()),
+        card.matches(),
+        card.worth()
+    ))
+}
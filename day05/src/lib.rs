@@ -0,0 +1,387 @@
+use std::{cmp, ops::Range, str::FromStr};
+
+use common::{
+    parse::{self, ParseError},
+    query::Command,
+    Solution,
+};
+
+mod queries;
+
+/// A line of a map like `50 98 2`: numbers in `98..100` are moved by `50 - 98`.
+#[derive(Debug)]
+pub struct Rule {
+    in_range: Range<i64>,
+    transform: i64,
+}
+
+impl Rule {
+    /// The numbers this rule applies to.
+    pub fn source_range(&self) -> Range<i64> {
+        self.in_range.clone()
+    }
+
+    /// What the rule adds to the numbers it applies to.
+    pub fn offset(&self) -> i64 {
+        self.transform
+    }
+
+    /// Splits `ranges` into the parts this rule applies to, already moved, and the rest.
+    pub fn transform(&self, ranges: Vec<Range<i64>>) -> (Vec<Range<i64>>, Vec<Range<i64>>) {
+        ranges
+            .into_iter()
+            .map(|range| {
+                let overlap = (cmp::max(range.start, self.in_range.start) + self.transform)
+                    ..(cmp::min(range.end, self.in_range.end) + self.transform);
+
+                let before = range.start..cmp::min(self.in_range.start, range.end);
+                let after = cmp::max(self.in_range.end, range.start)..range.end;
+
+                (overlap, [before, after])
+            })
+            .fold(
+                (Vec::new(), Vec::new()),
+                |(mut transformed, mut not_transformed), (overlap, [before, after])| {
+                    if !overlap.is_empty() {
+                        transformed.push(overlap);
+                    }
+                    if !before.is_empty() {
+                        not_transformed.push(before);
+                    }
+                    if !after.is_empty() {
+                        not_transformed.push(after);
+                    }
+
+                    (transformed, not_transformed)
+                },
+            )
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [out_range_start, in_range_start, range_length] = s
+            .split_whitespace()
+            .map(parse::number)
+            .collect::<Result<Vec<i64>, _>>()?
+            .try_into()
+            .map_err(|_| ParseError::new(s, "three numbers"))?;
+
+        let in_range_end = in_range_start.checked_add(range_length);
+        let out_range_end = out_range_start.checked_add(range_length);
+        let transform = out_range_start.checked_sub(in_range_start);
+        let (Some(in_range_end), Some(_), Some(transform)) =
+            (in_range_end, out_range_end, transform)
+        else {
+            return Err(ParseError::new(s, "ranges that fit into 64 bits"));
+        };
+
+        Ok(Rule {
+            in_range: in_range_start..in_range_end,
+            transform,
+        })
+    }
+}
+
+/// A category of the almanac.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum Kind {
+    #[default]
+    Seed,
+    Location,
+    Soil,
+    Fertilizer,
+    Water,
+    Light,
+    Temperature,
+    Humidity,
+}
+
+impl FromStr for Kind {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "soil" => Kind::Soil,
+            "fertilizer" => Kind::Fertilizer,
+            "water" => Kind::Water,
+            "light" => Kind::Light,
+            "temperature" => Kind::Temperature,
+            "humidity" => Kind::Humidity,
+            "location" => Kind::Location,
+            "seed" => Kind::Seed,
+            unknown => {
+                return Err(ParseError::new(
+                    unknown,
+                    "a category like \"seed\" or \"soil\"",
+                ))
+            }
+        })
+    }
+}
+/// The name of `kind` as the almanac writes it.
+pub(crate) fn category(kind: Kind) -> String {
+    format!("{kind:?}").to_lowercase()
+}
+
+/// A map like `seed-to-soil map:` with its rules.
+pub struct Map {
+    from: Kind,
+    to: Kind,
+    rules: Vec<Rule>,
+}
+
+impl Map {
+    pub fn from(&self) -> Kind {
+        self.from
+    }
+
+    pub fn to(&self) -> Kind {
+        self.to
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Converts numbers of the category this map starts at to the one it leads to. Numbers no
+    /// rule applies to stay the same. Returns `None` if `entry` is of another category.
+    pub fn convert(&self, entry: Entry) -> Option<Entry> {
+        if entry.kind != self.from {
+            return None;
+        }
+
+        let (mut transformed, not_yet_transformed) = self.rules.iter().fold(
+            (Vec::new(), entry.ranges),
+            |(mut already_transformed, not_yet_transformed), rule| {
+                let (newly_transformed, not_yet_transformed) = rule.transform(not_yet_transformed);
+
+                already_transformed.extend(newly_transformed);
+
+                (already_transformed, not_yet_transformed)
+            },
+        );
+
+        transformed.extend_from_slice(&not_yet_transformed);
+
+        Some(Entry {
+            kind: self.to,
+            ranges: transformed,
+        })
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = parse::lines(s);
+        let header = lines
+            .next()
+            .ok_or_else(|| ParseError::new(s, "a map header"))?;
+        let (from, to) = parse::split_once(parse::strip_suffix(header, " map:")?, "-to-")?;
+
+        let from = from.parse()?;
+        let to = to.parse()?;
+
+        let rules = lines.map(str::parse).collect::<Result<_, _>>()?;
+
+        Ok(Map { from, to, rules })
+    }
+}
+
+/// Ranges of numbers of one category.
+#[derive(Debug, Default)]
+pub struct Entry {
+    pub kind: Kind,
+    pub ranges: Vec<Range<i64>>,
+}
+
+/// The seeds to plant and the maps that lead from seeds to locations.
+pub struct Almanac {
+    seeds: Vec<i64>,
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    pub fn seeds(&self) -> &[i64] {
+        &self.seeds
+    }
+
+    pub fn maps(&self) -> &[Map] {
+        &self.maps
+    }
+
+    /// Every seed number on its own.
+    pub fn single_seeds(&self) -> Entry {
+        Entry {
+            kind: Kind::Seed,
+            ranges: self.seeds.iter().map(|&seed| seed..(seed + 1)).collect(),
+        }
+    }
+
+    /// The seed numbers read as pairs of range start and range length.
+    pub fn seed_ranges(&self) -> Entry {
+        Entry {
+            kind: Kind::Seed,
+            ranges: self
+                .seeds
+                .chunks_exact(2)
+                .map(|pair| pair[0]..(pair[0] + pair[1]))
+                .collect(),
+        }
+    }
+
+    /// The lowest location any of `items` ends up at, or `None` if there are no items or their
+    /// category does not lead to a location.
+    pub fn nearest_location(&self, mut items: Entry) -> Option<i64> {
+        // Unless the maps go round in circles, every map is used at most once on the way.
+        for _ in 0..=self.maps.len() {
+            if items.kind == Kind::Location {
+                return items.ranges.into_iter().map(|r| r.start).min();
+            }
+            items = self.convert(items)?;
+        }
+
+        None
+    }
+
+    /// Converts `entry` with the map that starts at its category, or returns `None` if there is
+    /// no such map.
+    pub fn convert(&self, entry: Entry) -> Option<Entry> {
+        let map = self.maps.iter().find(|map| map.from == entry.kind)?;
+        map.convert(entry)
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut entries = parse::paragraphs(s);
+        let seeds_text = parse::strip_prefix(entries.next().unwrap_or(&s[s.len()..]), "seeds: ")?;
+        let numbers: Vec<_> = seeds_text.split_whitespace().collect();
+        let seeds = numbers
+            .iter()
+            .map(|number| parse::number(number))
+            .collect::<Result<Vec<i64>, _>>()?;
+
+        // Part two reads the seeds as ranges, which have to hold at least one seed each.
+        if seeds.is_empty() || seeds.len() % 2 != 0 {
+            return Err(ParseError::new(
+                seeds_text,
+                "pairs of numbers, a start and a length each",
+            ));
+        }
+        for (pair, text) in seeds.chunks_exact(2).zip(numbers.chunks_exact(2)) {
+            if pair[1] < 1 {
+                return Err(ParseError::new(text[1], "a length of at least 1"));
+            }
+            if pair[0].checked_add(pair[1]).is_none() {
+                return Err(ParseError::new(text[1], "a range that fits into 64 bits"));
+            }
+        }
+
+        let paragraphs: Vec<_> = entries.collect();
+        let maps: Vec<Map> = paragraphs
+            .iter()
+            .map(|paragraph| paragraph.parse())
+            .collect::<Result<_, _>>()?;
+
+        // Every category from seed on has to lead on to the next one, until location.
+        let mut kind = Kind::Seed;
+        let mut passed = Vec::new();
+        while kind != Kind::Location {
+            passed.push(kind);
+            let Some(i) = maps.iter().position(|map| map.from == kind) else {
+                return Err(ParseError::new(
+                    &s[s.len()..],
+                    format!("a map from {} on", category(kind)),
+                ));
+            };
+            if passed.contains(&maps[i].to) {
+                return Err(ParseError::new(
+                    paragraphs[i].lines().next().unwrap_or_default(),
+                    "a map that leads on to location instead of back",
+                ));
+            }
+            kind = maps[i].to;
+        }
+
+        Ok(Self { seeds, maps })
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Answer = i64;
+
+    const COMMANDS: &'static [Command] = queries::COMMANDS;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        input.parse()
+    }
+
+    fn part_one(almanac: &Almanac) -> i64 {
+        almanac
+            .nearest_location(almanac.single_seeds())
+            .expect("parsing checked that the seeds lead to a location")
+    }
+
+    fn part_two(almanac: &Almanac) -> i64 {
+        almanac
+            .nearest_location(almanac.seed_ranges())
+            .expect("parsing checked that the seed ranges lead to a location")
+    }
+
+    fn query(almanac: &Almanac, command: &str, arguments: &[&str]) -> Result<String, String> {
+        queries::query(almanac, command, arguments)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::Solver;
+
+    use crate::Day05;
+
+    #[test]
+    fn parse_errors() {
+        let error = |input| {
+            Solver::of::<Day05>()
+                .parse(input)
+                .err()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(
+            error("seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n"),
+            "line 5, column 1: expected a map from soil on, found end of line"
+        );
+        assert_eq!(
+            error("seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n\nsoil-to-seed map:\n1 2 3\n"),
+            "line 6, column 1: expected a map that leads on to location instead of back, found \"soil-to-seed map:\""
+        );
+        assert_eq!(
+            error("seeds: 1\n\nseed-to-location map:\n1 2 3\n"),
+            "line 1, column 8: expected pairs of numbers, a start and a length each, found \"1\""
+        );
+        assert_eq!(
+            error("seeds: 1 0\n\nseed-to-location map:\n1 2 3\n"),
+            "line 1, column 10: expected a length of at least 1, found \"0\""
+        );
+        assert_eq!(
+            error("seeds: 1 2\n\nseed-to-location map:\n1 2\n"),
+            "line 4, column 1: expected three numbers, found \"1 2\""
+        );
+        assert_eq!(
+            error("seeds: 1 2\n\nseed-to-location map:\n9223372036854775807 2 3\n"),
+            "line 4, column 1: expected ranges that fit into 64 bits, found \"9223372036854775807 2 3\""
+        );
+    }
+}
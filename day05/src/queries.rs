@@ -0,0 +1,84 @@
+use common::query::{arguments, number, Command};
+
+use crate::{category, Almanac, Kind};
+
+pub(crate) const COMMANDS: &[Command] = &[
+    Command {
+        name: "seeds",
+        arguments: "",
+        help: "list the seeds",
+    },
+    Command {
+        name: "maps",
+        arguments: "",
+        help: "list the maps and how many rules they have",
+    },
+    Command {
+        name: "trace",
+        arguments: "<seed>",
+        help: "follow a seed to its location, showing the rule of every step",
+    },
+];
+
+pub(crate) fn query(almanac: &Almanac, command: &str, args: &[&str]) -> Result<String, String> {
+    match command {
+        "seeds" => {
+            arguments::<0>(args, "seeds")?;
+            Ok(format!("{:?}", almanac.seeds()))
+        }
+        "maps" => {
+            arguments::<0>(args, "maps")?;
+            Ok(almanac
+                .maps()
+                .iter()
+                .map(|map| {
+                    format!(
+                        "{}-to-{}: {} rules",
+                        category(map.from()),
+                        category(map.to()),
+                        map.rules().len()
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+        "trace" => {
+            let [seed] = arguments(args, "trace <seed>")?;
+            let mut value: i64 = number(seed)?;
+            let mut kind = Kind::Seed;
+
+            let mut steps = Vec::new();
+            while kind != Kind::Location {
+                let map = almanac
+                    .maps()
+                    .iter()
+                    .find(|map| map.from() == kind)
+                    .ok_or_else(|| format!("there is no map from {}", category(kind)))?;
+                let rule = map
+                    .rules()
+                    .iter()
+                    .find(|rule| rule.source_range().contains(&value));
+
+                let next = value + rule.map_or(0, |rule| rule.offset());
+                let reason = match rule {
+                    Some(rule) => {
+                        let range = rule.source_range();
+                        format!("by rule {}..{} {:+}", range.start, range.end, rule.offset())
+                    }
+                    None => "unchanged, no rule applies".to_string(),
+                };
+                steps.push(format!(
+                    "{} {value} -> {} {next} {reason}",
+                    category(kind),
+                    category(map.to())
+                ));
+
+                (kind, value) = (map.to(), next);
+            }
+            steps.push(format!("location {value}"));
+
+            Ok(steps.join("\n"))
+        }
+        _ => unreachable!("{command} is not one of the commands"),
+    }
+}
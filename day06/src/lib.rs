@@ -0,0 +1,195 @@
+use std::{cmp, str::FromStr};
+
+use common::{
+    parse::{self, ParseError},
+    query::Command,
+    Solution,
+};
+
+mod queries;
+
+/// A boat race: how long it lasts and the farthest distance travelled in it so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: i64,
+    pub record: u64,
+}
+
+impl Race {
+    /// In how many ways the button can be held to beat the record.
+    pub fn margin_of_error(&self) -> u64 {
+        let p_halves = self.time as f64 / 2.0;
+        let sqrt_term = (p_halves.powi(2) - self.record as f64).sqrt();
+        let [begin, end] = [
+            cmp::max((p_halves - sqrt_term).floor() as i64 + 1, 0),
+            cmp::min((p_halves + sqrt_term).ceil() as i64 - 1, self.time),
+        ];
+
+        if begin <= end {
+            end.abs_diff(begin) + 1
+        } else {
+            0
+        }
+    }
+}
+
+/// The races of a sheet, in order.
+#[derive(Debug)]
+pub struct RaceData {
+    races: Vec<Race>,
+    /// The numbers of all races read as a single race, which parsing checks to fit.
+    joined: Race,
+}
+
+impl RaceData {
+    pub fn races(&self) -> &[Race] {
+        &self.races
+    }
+
+    /// Reads the numbers of all races as a single race, ignoring the spaces between them.
+    pub fn joined(&self) -> RaceData {
+        RaceData {
+            races: vec![self.joined],
+            joined: self.joined,
+        }
+    }
+
+    /// The margins of error of all races multiplied together.
+    pub fn product_of_error_margin(&self) -> u64 {
+        self.races
+            .iter()
+            .map(|r| r.margin_of_error())
+            .inspect(|margin| tracing::debug!(margin, "margin of error"))
+            .product()
+    }
+}
+
+impl FromStr for RaceData {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = parse::lines(s);
+        let end = &s[s.len()..];
+
+        let times_text = parse::strip_prefix(lines.next().unwrap_or(end), "Time:")?;
+        let times = times_text
+            .split_whitespace()
+            .map(parse::number)
+            .collect::<Result<Vec<i64>, _>>()?;
+        let records_line = lines.next().unwrap_or(end);
+        let records_text = parse::strip_prefix(records_line, "Distance:")?;
+        let records = records_text
+            .split_whitespace()
+            .map(parse::number)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if times.is_empty() {
+            return Err(ParseError::new(times_text, "at least one time"));
+        }
+        if times.len() != records.len() {
+            return Err(ParseError::new(
+                records_line,
+                format!("a distance for each of the {} times", times.len()),
+            ));
+        }
+
+        let joined_time = times
+            .iter()
+            .map(|&time| u64::try_from(time).ok())
+            .collect::<Option<Vec<_>>>()
+            .and_then(|times| concatenate(&times))
+            .and_then(|time| i64::try_from(time).ok())
+            .ok_or_else(|| ParseError::new(times_text, "times that still fit when joined"))?;
+        let joined_record = concatenate(&records).ok_or_else(|| {
+            ParseError::new(records_text, "records_text that still fit when joined")
+        })?;
+
+        let races = times
+            .into_iter()
+            .zip(records)
+            .map(|(time, record)| Race { time, record })
+            .collect();
+        let joined = Race {
+            time: joined_time,
+            record: joined_record,
+        };
+
+        Ok(RaceData { races, joined })
+    }
+}
+
+/// Writes `numbers` one after the other and reads the digits as a single number, if it fits.
+fn concatenate(numbers: &[u64]) -> Option<u64> {
+    numbers.iter().try_fold(0u64, |joined, &number| {
+        let shift = 10u64.checked_pow(number.checked_ilog10().unwrap_or(0) + 1)?;
+        joined.checked_mul(shift)?.checked_add(number)
+    })
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = RaceData;
+    type Answer = u64;
+
+    const COMMANDS: &'static [Command] = queries::COMMANDS;
+
+    fn parse(input: &str) -> Result<RaceData, ParseError> {
+        input.parse()
+    }
+
+    fn part_one(data: &RaceData) -> u64 {
+        data.product_of_error_margin()
+    }
+
+    fn part_two(data: &RaceData) -> u64 {
+        data.joined().product_of_error_margin()
+    }
+
+    fn query(data: &RaceData, command: &str, arguments: &[&str]) -> Result<String, String> {
+        queries::query(data, command, arguments)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::{Solution, Solver};
+
+    use crate::Day06;
+
+    #[test]
+    fn example() {
+        let races = Day06::parse(include_str!("test_input")).unwrap();
+
+        assert_eq!(Day06::part_one(&races), 288);
+        assert_eq!(Day06::part_two(&races), 71503);
+    }
+
+    #[test]
+    fn numbers_that_do_not_join() {
+        let error = |input| {
+            Solver::of::<Day06>()
+                .parse(input)
+                .err()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(
+            error("Time:\nDistance:\n"),
+            "line 1, column 6: expected at least one time, found end of line"
+        );
+        assert_eq!(
+            error("Time: 1 99999999999999999999\nDistance: 1 2\n"),
+            "line 1, column 9: expected a number, found \"99999999999999999999\""
+        );
+        assert_eq!(
+            error("Time: 1000000000 1000000000\nDistance: 1 2\n"),
+            "line 1, column 6: expected times that still fit when joined, found \" 1000000000 1000000000\""
+        );
+        assert_eq!(
+            error("Time: 1 2\nDistance: 18446744073709551615 1\n"),
+            "line 2, column 10: expected records_text that still fit when joined, found \" 18446744073709551615 1\""
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use common::query::{arguments, number, Command};
+
+use crate::RaceData;
+
+pub(crate) const COMMANDS: &[Command] = &[
+    Command {
+        name: "race",
+        arguments: "<n>",
+        help: "show race n, counting from 1, and in how many ways it can be won",
+    },
+    Command {
+        name: "joined",
+        arguments: "",
+        help: "show the single race of part two",
+    },
+];
+
+pub(crate) fn query(races: &RaceData, command: &str, args: &[&str]) -> Result<String, String> {
+    let joined;
+    let race = match command {
+        "race" => {
+            let [n] = arguments(args, "race <n>")?;
+            let n: usize = number(n)?;
+            n.checked_sub(1)
+                .and_then(|i| races.races().get(i))
+                .ok_or_else(|| format!("there is no race {n}"))?
+        }
+        "joined" => {
+            arguments::<0>(args, "joined")?;
+            joined = races.joined();
+            &joined.races()[0]
+        }
+        _ => unreachable!("{command} is not one of the commands"),
+    };
+
+    Ok(format!(
+        "{}ms, record {}mm: {} ways to win",
+        race.time,
+        race.record,
+        race.margin_of_error()
+    ))
+}
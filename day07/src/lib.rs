@@ -0,0 +1,342 @@
+use common::{
+    parse::{self, ParseError},
+    query::Command,
+    Solution,
+};
+use itertools::Itertools;
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    fmt::{Debug, Write},
+    str::FromStr,
+};
+
+mod queries;
+
+/// A Camel Cards card, ordered by strength. A Joker is the weakest card but can stand in for any
+/// other when the kind of a hand is determined.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Card {
+    Joker,
+    Two = 2,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Debug for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Card::Two => '2',
+            Card::Three => '3',
+            Card::Four => '4',
+            Card::Five => '5',
+            Card::Six => '6',
+            Card::Seven => '7',
+            Card::Eight => '8',
+            Card::Nine => '9',
+            Card::Ten => 'T',
+            Card::Jack | Card::Joker => 'J',
+            Card::Queen => 'Q',
+            Card::King => 'K',
+            Card::Ace => 'A',
+        };
+
+        f.write_char(c)
+    }
+}
+
+impl TryFrom<char> for Card {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            'A' => Card::Ace,
+            'K' => Card::King,
+            'Q' => Card::Queen,
+            'J' => Card::Jack,
+            'T' => Card::Ten,
+            '9' => Card::Nine,
+            '8' => Card::Eight,
+            '7' => Card::Seven,
+            '6' => Card::Six,
+            '5' => Card::Five,
+            '4' => Card::Four,
+            '3' => Card::Three,
+            '2' => Card::Two,
+            unsupported => return Err(unsupported),
+        })
+    }
+}
+
+/// The kind of a hand, ordered by strength.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum HandKind {
+    HighCard,
+    Pair,
+    TwoPairs,
+    ThreeOf,
+    FullHouse,
+    FourOf,
+    FiveOf,
+}
+
+/// Five cards, ordered by their kind first and by their cards from left to right second.
+#[derive(PartialEq, Eq, Clone)]
+pub struct Hand {
+    cards: [Card; 5],
+}
+
+impl Debug for Hand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Hand { \"")?;
+
+        for card in self.cards {
+            write!(f, "{:?}", card)?;
+        }
+
+        f.write_str("\" }")
+    }
+}
+
+impl Hand {
+    pub fn cards(&self) -> [Card; 5] {
+        self.cards
+    }
+
+    /// Reads every Jack of this hand as a Joker instead.
+    pub fn with_jokers(&self) -> Hand {
+        Hand {
+            cards: self.cards.map(|card| match card {
+                Card::Jack => Card::Joker,
+                other => other,
+            }),
+        }
+    }
+
+    pub fn kind(&self) -> HandKind {
+        let mut counts = HashMap::<Card, u32>::new();
+
+        for card in self.cards {
+            *counts.entry(card).or_default() += 1;
+        }
+
+        let joker_count = counts.remove(&Card::Joker).unwrap_or_default();
+        let mut counts: Vec<_> = counts.into_values().collect();
+        counts.sort_unstable_by_key(|&c| Reverse(c));
+
+        match (
+            counts.first().copied().unwrap_or_default() + joker_count,
+            counts.get(1).copied().unwrap_or_default(),
+        ) {
+            (5, _) => HandKind::FiveOf,
+            (4, _) => HandKind::FourOf,
+            (3, 2) => HandKind::FullHouse,
+            (3, _) => HandKind::ThreeOf,
+            (2, 2) => HandKind::TwoPairs,
+            (2, _) => HandKind::Pair,
+            (1, _) => HandKind::HighCard,
+            should_not_happen => unreachable!("{should_not_happen:?}"),
+        }
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards = s
+            .char_indices()
+            .map(|(i, c)| {
+                Card::try_from(c).map_err(|c| {
+                    ParseError::new(&s[i..i + c.len_utf8()], "a card like \"A\", \"T\" or \"2\"")
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+            .map_err(|_| ParseError::new(s, "a hand of five cards"))?;
+
+        Ok(Hand { cards })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::Solver;
+
+    use crate::{Day07, Hand, HandKind};
+
+    #[test]
+    fn parse_errors() {
+        let error = |input| {
+            Solver::of::<Day07>()
+                .parse(input)
+                .err()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(
+            error("32T3K 765\nKTJJX 220\n"),
+            "line 2, column 5: expected a card like \"A\", \"T\" or \"2\", found \"X\""
+        );
+        assert_eq!(
+            error("32T3K\n"),
+            "line 1, column 6: expected \" \", found end of line"
+        );
+    }
+
+    #[test]
+    fn parsing() {
+        let inputs = [
+            ("32T3K", HandKind::Pair),
+            ("T55J5", HandKind::FourOf),
+            ("KK677", HandKind::TwoPairs),
+            ("KTJJT", HandKind::FourOf),
+            ("QQQJA", HandKind::FourOf),
+        ]
+        .map(|(hand, kind)| (hand.parse::<Hand>().unwrap().with_jokers(), kind));
+
+        for (hand, kind) in inputs {
+            assert_eq!(hand.kind(), kind, "{hand:?} did not evaluate as {kind:?}",);
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        let inputs = [
+            ("32T3K", 1),
+            ("KK677", 2),
+            ("T55J5", 3),
+            ("QQQJA", 4),
+            ("KTJJT", 5),
+        ]
+        .map(|(hand, rank)| (hand.parse::<Hand>().unwrap().with_jokers(), rank));
+
+        for (hand, rank) in &inputs {
+            for (other, other_rank) in &inputs {
+                assert_eq!(hand.clone().cmp(other), rank.cmp(other_rank));
+                assert_eq!(other.clone().cmp(hand), other_rank.cmp(rank));
+            }
+        }
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(HandKind::Pair <= HandKind::ThreeOf);
+        assert!(HandKind::TwoPairs <= HandKind::ThreeOf);
+        assert!(HandKind::ThreeOf == HandKind::ThreeOf);
+        assert!(HandKind::ThreeOf <= HandKind::FourOf);
+        assert!(HandKind::ThreeOf <= HandKind::FiveOf);
+    }
+}
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.kind()
+            .cmp(&other.kind())
+            .then_with(|| self.cards.cmp(&other.cards))
+    }
+}
+
+/// Every hand of a game of Camel Cards together with its bid.
+#[derive(Debug)]
+pub struct Game {
+    games: Vec<(Hand, u32)>,
+}
+
+impl Game {
+    pub fn hands(&self) -> &[(Hand, u32)] {
+        &self.games
+    }
+
+    /// Plays the same hands again, with every Jack acting as a Joker.
+    pub fn with_jokers(&self) -> Game {
+        Game {
+            games: self
+                .games
+                .iter()
+                .map(|(hand, bid)| (hand.with_jokers(), *bid))
+                .collect(),
+        }
+    }
+
+    /// The sum of every bid multiplied by the rank of its hand, the weakest hand being rank 1.
+    pub fn total_winnings(&self) -> u32 {
+        let games = self
+            .games
+            .iter()
+            .sorted_unstable_by(|(a, _), (b, _)| a.cmp(b))
+            .collect_vec();
+
+        games
+            .iter()
+            .enumerate()
+            .inspect(|(i, (hand, bid))| {
+                tracing::debug!(
+                    ?hand,
+                    rank = i + 1,
+                    bid,
+                    winnings = (*i as u32 + 1) * bid,
+                    "ranked hand"
+                );
+            })
+            .map(|(i, (_, bid))| (i as u32 + 1) * bid)
+            .sum()
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let games = parse::lines(s)
+            .map(|l| {
+                let (hand, bid) = parse::split_once(l, " ")?;
+
+                Ok((hand.parse::<Hand>()?, parse::number(bid)?))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Game { games })
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Game;
+    type Answer = u32;
+
+    const COMMANDS: &'static [Command] = queries::COMMANDS;
+
+    fn parse(input: &str) -> Result<Game, ParseError> {
+        input.trim().parse()
+    }
+
+    fn part_one(game: &Game) -> u32 {
+        game.total_winnings()
+    }
+
+    fn part_two(game: &Game) -> u32 {
+        game.with_jokers().total_winnings()
+    }
+
+    fn query(game: &Game, command: &str, arguments: &[&str]) -> Result<String, String> {
+        queries::query(game, command, arguments)
+    }
+}
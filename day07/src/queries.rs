@@ -0,0 +1,47 @@
+use common::query::{arguments, number, Command};
+
+use crate::{Game, Hand};
+
+fn kinds(hand: &Hand) -> String {
+    format!(
+        "{:?}, or {:?} with jokers",
+        hand.kind(),
+        hand.with_jokers().kind()
+    )
+}
+
+pub(crate) const COMMANDS: &[Command] = &[
+    Command {
+        name: "kind",
+        arguments: "<cards>",
+        help: "show the kind of any hand, like `kind KTJJT`",
+    },
+    Command {
+        name: "hand",
+        arguments: "<n>",
+        help: "show hand n of the input, counting from 1, with its bid and kind",
+    },
+];
+
+pub(crate) fn query(game: &Game, command: &str, args: &[&str]) -> Result<String, String> {
+    match command {
+        "kind" => {
+            let [cards] = arguments(args, "kind <cards>")?;
+            let hand: Hand = cards.parse().map_err(|err| format!("{err}"))?;
+
+            Ok(kinds(&hand))
+        }
+        "hand" => {
+            let [n] = arguments(args, "hand <n>")?;
+            let n: usize = number(n)?;
+            let (hand, bid) = n
+                .checked_sub(1)
+                .and_then(|i| game.hands().get(i))
+                .ok_or_else(|| format!("there is no hand {n}"))?;
+
+            let cards = hand.cards().map(|card| format!("{card:?}")).concat();
+            Ok(format!("{cards} bids {bid}: {}", kinds(hand)))
+        }
+        _ => unreachable!("{command} is not one of the commands"),
+    }
+}
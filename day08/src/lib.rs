@@ -0,0 +1,372 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    str::{self, FromStr},
+};
+
+use common::{
+    parse::{self, ParseError},
+    query::Command,
+    Solution,
+};
+use num::integer::lcm;
+
+mod queries;
+
+/// Which way to go at a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'l' | 'L' => Ok(Direction::Left),
+            'r' | 'R' => Ok(Direction::Right),
+            uncovered => Err(uncovered),
+        }
+    }
+}
+
+/// Parses the three letter name of a node.
+fn node_name(s: &str) -> Result<[u8; 3], ParseError> {
+    s.as_bytes()
+        .try_into()
+        .map_err(|_| ParseError::new(s, "a node name of three letters"))
+}
+
+/// A node of the network, like `AAA = (BBB, CCC)`.
+pub struct Node {
+    name: [u8; 3],
+    left: [u8; 3],
+    right: [u8; 3],
+}
+
+impl Node {
+    pub fn name(&self) -> &str {
+        str::from_utf8(&self.name).unwrap()
+    }
+
+    /// The node to go to when turning left.
+    pub fn left(&self) -> &str {
+        str::from_utf8(&self.left).unwrap()
+    }
+
+    /// The node to go to when turning right.
+    pub fn right(&self) -> &str {
+        str::from_utf8(&self.right).unwrap()
+    }
+}
+
+impl std::hash::Hash for Node {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state)
+    }
+}
+
+impl std::fmt::Debug for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} = ({}, {})", self.name(), self.left(), self.right())
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.name.cmp(&other.name)
+    }
+}
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for Node {}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, connections) = parse::split_once(s, " = ")?;
+        let connections = parse::strip_prefix(connections, "(")?;
+        let (left, right) = parse::split_once(parse::strip_suffix(connections, ")")?, ", ")?;
+
+        let name = node_name(name)?;
+        let left = node_name(left)?;
+        let right = node_name(right)?;
+
+        Ok(Node { name, left, right })
+    }
+}
+
+/// The map of the desert: directions to follow, repeated forever, and the network of nodes.
+#[derive(Debug)]
+pub struct Input {
+    directions: Vec<Direction>,
+    /// Sorted by name.
+    network: Vec<Node>,
+    /// The indices of the left and the right node of every node of the network.
+    links: Vec<[usize; 2]>,
+}
+
+impl Input {
+    pub fn directions(&self) -> &[Direction] {
+        &self.directions
+    }
+
+    /// Every node, sorted by name.
+    pub fn network(&self) -> &[Node] {
+        &self.network
+    }
+
+    pub fn node(&self, name: &str) -> Option<&Node> {
+        let i = self
+            .network
+            .binary_search_by(|node| node.name.as_slice().cmp(name.as_bytes()))
+            .ok()?;
+
+        Some(&self.network[i])
+    }
+
+    fn index(&self, name: &[u8; 3]) -> Option<usize> {
+        self.network
+            .binary_search_by(|node| node.name.cmp(name))
+            .ok()
+    }
+
+    /// The index of the node that `direction` leads to from the node at index `i`.
+    fn next(&self, i: usize, direction: Direction) -> usize {
+        let [left, right] = self.links[i];
+        match direction {
+            Direction::Left => left,
+            Direction::Right => right,
+        }
+    }
+
+    /// Steps needed to walk from `AAA` to `ZZZ`, or `None` if the directions never get there.
+    pub fn steps_to_zzz(&self) -> Option<usize> {
+        let mut current = self.index(b"AAA")?;
+        let end = self.index(b"ZZZ")?;
+
+        // Once every node was reached at every point of the directions, the walk repeats itself.
+        let limit = self.directions.len() * self.network.len();
+        for (steps, &direction) in self.directions.iter().cycle().enumerate().take(limit) {
+            if current == end {
+                return Some(steps);
+            }
+            current = self.next(current, direction);
+        }
+
+        None
+    }
+
+    /// Steps needed until walking from every node ending in `A` at once ends up only at nodes
+    /// ending in `Z`.
+    ///
+    /// This relies on a property of the puzzle inputs: every walk runs into a loop that passes
+    /// exactly one node ending in `Z`, at a step that is a multiple of the loop length. Returns
+    /// `None` if the network does not have that property.
+    pub fn required_steps(&self) -> Option<usize> {
+        let starting_nodes = (0..self.network.len()).filter(|&i| self.network[i].name[2] == b'A');
+
+        starting_nodes
+            .map(|node| {
+                let mut already_visited = HashMap::new();
+
+                let mut current_node = node;
+                let mut steps = 0;
+                let mut current_direction_step;
+
+                'outer: loop {
+                    current_direction_step = 0;
+                    for (i, &direction) in self.directions.iter().enumerate() {
+                        match already_visited.entry((i, current_node)) {
+                            Entry::Occupied(_) => break 'outer,
+                            Entry::Vacant(entry) => {
+                                entry.insert(steps);
+                            }
+                        }
+                        current_node = self.next(current_node, direction);
+
+                        steps += 1;
+                        current_direction_step += 1;
+                    }
+                }
+                let loop_start = already_visited[&(current_direction_step, current_node)];
+                let loop_end = steps;
+
+                let [critical_point]: [usize; 1] = already_visited
+                    .into_iter()
+                    .filter_map(|((_, node), step)| {
+                        (self.network[node].name[2] == b'Z'
+                            && (loop_start..loop_end).contains(&step))
+                        .then_some(step)
+                    })
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .ok()?;
+
+                (critical_point == loop_end - loop_start).then_some(critical_point)
+            })
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .reduce(lcm)
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut paragraphs = parse::paragraphs(s);
+        let directions = paragraphs.next().unwrap_or(&s[s.len()..]);
+        let network: Vec<&str> = paragraphs.collect();
+        if network.is_empty() {
+            return Err(ParseError::new(
+                &s[s.len()..],
+                "a blank line followed by the network",
+            ));
+        }
+
+        let directions = directions
+            .char_indices()
+            .map(|(i, c)| {
+                Direction::try_from(c).map_err(|c| {
+                    ParseError::new(&directions[i..i + c.len_utf8()], "\"L\" or \"R\"")
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let lines: Vec<_> = network.into_iter().flat_map(parse::lines).collect();
+        let mut network: Vec<(Node, &str)> = lines
+            .iter()
+            .map(|line| Ok((line.parse()?, *line)))
+            .collect::<Result<_, ParseError>>()?;
+
+        network.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        if let Some(pair) = network.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            return Err(ParseError::new(
+                pair[1].1,
+                format!("only a single node {}", pair[1].0.name()),
+            ));
+        }
+        let (network, lines): (Vec<_>, Vec<_>) = network.into_iter().unzip();
+
+        let index = |name: &[u8; 3]| network.binary_search_by(|node| node.name.cmp(name)).ok();
+        let links = network
+            .iter()
+            .zip(&lines)
+            .map(
+                |(node, line)| match [index(&node.left), index(&node.right)] {
+                    [Some(left), Some(right)] => Ok([left, right]),
+                    _ => Err(ParseError::new(
+                        line,
+                        "nodes that lead to nodes of the network",
+                    )),
+                },
+            )
+            .collect::<Result<_, _>>()?;
+
+        let input = Self {
+            directions,
+            network,
+            links,
+        };
+
+        let end = &s[s.len()..];
+        for name in ["AAA", "ZZZ"] {
+            if input.node(name).is_none() {
+                return Err(ParseError::new(end, format!("a node {name}")));
+            }
+        }
+        if input.steps_to_zzz().is_none() {
+            let start = input.index(b"AAA").map_or(end, |i| lines[i]);
+            return Err(ParseError::new(
+                start,
+                "directions that lead from AAA to ZZZ",
+            ));
+        }
+
+        Ok(input)
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Input;
+    type Answer = usize;
+
+    const COMMANDS: &'static [Command] = queries::COMMANDS;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part_one(input: &Input) -> usize {
+        input
+            .steps_to_zzz()
+            .expect("parsing checked that the directions lead to ZZZ")
+    }
+
+    fn part_two(input: &Input) -> usize {
+        input
+            .required_steps()
+            .expect("the network does not have the shape part two relies on")
+    }
+
+    fn query(input: &Input, command: &str, arguments: &[&str]) -> Result<String, String> {
+        queries::query(input, command, arguments)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::Solver;
+
+    use crate::Day08;
+
+    #[test]
+    fn parse_errors() {
+        let error = |input| {
+            Solver::of::<Day08>()
+                .parse(input)
+                .err()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(
+
+            error("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n"),
+
+            "line 3, column 1: expected nodes that lead to nodes of the network, found \"AAA = (BBB, ZZZ)\""
+
+        );
+        assert_eq!(
+            error("LR\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n"),
+            "line 3, column 1: expected directions that lead from AAA to ZZZ, found \"AAA = (AAA, AAA)\""
+        );
+        assert_eq!(
+            error("LR\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n"),
+            "line 5, column 1: expected a node AAA, found end of line"
+        );
+        assert_eq!(
+            error("LR\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\nAAA = (AAA, AAA)\n"),
+            "line 5, column 1: expected only a single node AAA, found \"AAA = (AAA, AAA)\""
+        );
+        assert_eq!(
+            error("LX\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n"),
+            "line 1, column 2: expected \"L\" or \"R\", found \"X\""
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use common::query::{arguments, number, Command};
+
+use crate::{Direction, Input};
+
+pub(crate) const COMMANDS: &[Command] = &[
+    Command {
+        name: "node",
+        arguments: "<name>",
+        help: "show where a node leads",
+    },
+    Command {
+        name: "walk",
+        arguments: "<name> <steps>",
+        help: "follow the directions from a node and show every node on the way",
+    },
+];
+
+pub(crate) fn query(input: &Input, command: &str, args: &[&str]) -> Result<String, String> {
+    let find = |name: &str| {
+        input
+            .node(name)
+            .ok_or_else(|| format!("there is no node {name}"))
+    };
+
+    match command {
+        "node" => {
+            let [name] = arguments(args, "node <name>")?;
+            Ok(format!("{:?}", find(name)?))
+        }
+        "walk" => {
+            let [name, steps] = arguments(args, "walk <name> <steps>")?;
+            let steps: usize = number(steps)?;
+
+            let mut node = find(name)?;
+            let mut path = vec![node.name().to_string()];
+            for direction in input.directions().iter().cycle().take(steps) {
+                node = find(match direction {
+                    Direction::Left => node.left(),
+                    Direction::Right => node.right(),
+                })?;
+                path.push(node.name().to_string());
+            }
+
+            Ok(path.join(" -> "))
+        }
+        _ => unreachable!("{command} is not one of the commands"),
+    }
+}
@@ -0,0 +1,150 @@
+use std::str::FromStr;
+
+use common::{
+    parse::{self, ParseError},
+    query::Command,
+    Solution,
+};
+
+mod queries;
+
+/// The OASIS report: the history of every value, one per line.
+#[derive(Debug)]
+pub struct Report {
+    histories: Vec<Vec<i64>>,
+}
+
+impl Report {
+    pub fn histories(&self) -> &[Vec<i64>] {
+        &self.histories
+    }
+
+    /// Sum of the next value of every history.
+    pub fn forward_sum(&self) -> i64 {
+        self.histories
+            .iter()
+            .map(|history| {
+                differences(history)
+                    .iter()
+                    .filter_map(|row| row.last())
+                    .sum::<i64>()
+            })
+            .sum()
+    }
+
+    /// Sum of the value before the first one of every history.
+    pub fn extrapolated_sum(&self) -> i64 {
+        self.histories
+            .iter()
+            .map(|history| {
+                let rows = differences(history);
+                let previous = rows
+                    .iter()
+                    .rev()
+                    .filter_map(|row| row.first())
+                    .fold(0, |below, first| first - below);
+
+                tracing::trace!(?history, previous, "extrapolated history");
+                previous
+            })
+            .sum()
+    }
+}
+
+/// The history followed by the differences between its values, the differences between those and
+/// so on, down to the first row without anything but zeros.
+pub fn differences(history: &[i64]) -> Vec<Vec<i64>> {
+    let mut rows = vec![history.to_vec()];
+    while let Some(row) = rows
+        .last()
+        .filter(|row| row.iter().any(|&value| value != 0))
+    {
+        let next = row.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        rows.push(next);
+    }
+
+    rows
+}
+
+impl FromStr for Report {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let histories = parse::lines(s)
+            .map(|line| {
+                let history: Vec<_> = line
+                    .split_whitespace()
+                    .map(parse::number)
+                    .collect::<Result<_, _>>()?;
+
+                // Without a row of zeros there is nothing to extrapolate from.
+                if differences(&history).last().is_some_and(Vec::is_empty) {
+                    return Err(ParseError::new(
+                        line,
+                        "a history whose differences end in zeros",
+                    ));
+                }
+
+                Ok(history)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Report { histories })
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Report;
+    type Answer = i64;
+
+    const COMMANDS: &'static [Command] = queries::COMMANDS;
+
+    fn parse(input: &str) -> Result<Report, ParseError> {
+        input.trim().parse()
+    }
+
+    fn part_one(report: &Report) -> i64 {
+        report.forward_sum()
+    }
+
+    fn part_two(report: &Report) -> i64 {
+        report.extrapolated_sum()
+    }
+
+    fn query(report: &Report, command: &str, arguments: &[&str]) -> Result<String, String> {
+        queries::query(report, command, arguments)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::Solver;
+
+    use crate::Day09;
+
+    #[test]
+    fn parse_errors() {
+        let error = |input| {
+            Solver::of::<Day09>()
+                .parse(input)
+                .err()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(
+            error("0 3 6\n1 2 4\n"),
+            "line 2, column 1: expected a history whose differences end in zeros, found \"1 2 4\""
+        );
+        assert_eq!(
+            error("5\n"),
+            "line 1, column 1: expected a history whose differences end in zeros, found \"5\""
+        );
+        assert_eq!(
+            error("1 x 3\n"),
+            "line 1, column 3: expected a number, found \"x\""
+        );
+    }
+}
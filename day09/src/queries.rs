@@ -0,0 +1,30 @@
+use common::query::{arguments, number, Command};
+
+use crate::{differences, Report};
+
+pub(crate) const COMMANDS: &[Command] = &[Command {
+    name: "history",
+    arguments: "<n>",
+    help: "show history n, counting from 1, with its differences",
+}];
+
+pub(crate) fn query(report: &Report, _: &str, args: &[&str]) -> Result<String, String> {
+    let [n] = arguments(args, "history <n>")?;
+    let n: usize = number(n)?;
+    let history = n
+        .checked_sub(1)
+        .and_then(|i| report.histories().get(i))
+        .ok_or_else(|| format!("there is no history {n}"))?;
+
+    let rows = differences(history);
+
+    Ok(rows
+        .iter()
+        .enumerate()
+        .map(|(depth, row)| {
+            let values: Vec<_> = row.iter().map(|value| format!("{value:>4}")).collect();
+            format!("{}{}", "  ".repeat(depth), values.join(""))
+        })
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
@@ -0,0 +1,242 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{parse, query::Command, ParseError, Solution};
+
+mod queries;
+
+/// A sketch of the pipes, with a loop through the starting tile `S`.
+#[derive(Debug, Clone)]
+pub struct Field {
+    tiles: HashMap<(i64, i64), char>,
+    path: HashSet<(i64, i64)>,
+}
+
+impl Field {
+    /// Every tile that is not ground, by its position.
+    pub fn tiles(&self) -> &HashMap<(i64, i64), char> {
+        &self.tiles
+    }
+
+    /// The tile at `position`, or `None` for ground.
+    pub fn get(&self, position: &(i64, i64)) -> Option<&char> {
+        self.tiles.get(position)
+    }
+}
+
+/// The neighbours of a tile, each with the pipes that connect to the tile from there.
+const NEIGHBOURS: [((i64, i64), [char; 3]); 4] = [
+    ((-1, 0), ['-', 'L', 'F']),
+    ((1, 0), ['-', 'J', '7']),
+    ((0, -1), ['|', 'F', '7']),
+    ((0, 1), ['|', 'L', 'J']),
+];
+
+/// The tiles next to `(x, y)` whose pipes connect to it.
+fn connections(tiles: &HashMap<(i64, i64), char>, (x, y): (i64, i64)) -> Vec<(i64, i64)> {
+    NEIGHBOURS
+        .iter()
+        .map(|&((dx, dy), pipes)| ((x + dx, y + dy), pipes))
+        .filter(|(pos, pipes)| tiles.get(pos).is_some_and(|c| pipes.contains(c)))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+/// The two directions `pipe` leads in.
+fn exits(pipe: char) -> &'static [(i64, i64)] {
+    match pipe {
+        '|' => &[(0, -1), (0, 1)],
+        '-' => &[(-1, 0), (1, 0)],
+        'L' => &[(0, -1), (1, 0)],
+        'J' => &[(0, -1), (-1, 0)],
+        '7' => &[(0, 1), (-1, 0)],
+        'F' => &[(0, 1), (1, 0)],
+        _ => &[],
+    }
+}
+
+/// Follows the pipes from `start` until it is reached again and returns every tile on the way,
+/// or the position of the first pipe that does not lead on to another one connecting back to it.
+fn walk(
+    tiles: &HashMap<(i64, i64), char>,
+    start: (i64, i64),
+) -> Result<HashSet<(i64, i64)>, (i64, i64)> {
+    let mut old = start;
+    let mut current = connections(tiles, start)[0];
+
+    let mut path: HashSet<_> = [old, current].into();
+    while current != start {
+        let (x, y) = current;
+        let leads_to: Vec<_> = exits(tiles[&current])
+            .iter()
+            .map(|(dx, dy)| (x + dx, y + dy))
+            .collect();
+        let next = match leads_to[..] {
+            [back, next] | [next, back] if back == old => next,
+            _ => return Err(current),
+        };
+        let connects_back = tiles.get(&next).is_some_and(|&c| {
+            c == 'S'
+                || exits(c)
+                    .iter()
+                    .any(|&(dx, dy)| (next.0 + dx, next.1 + dy) == current)
+        });
+        if !connects_back {
+            return Err(current);
+        }
+
+        old = current;
+        current = next;
+        path.insert(current);
+    }
+
+    Ok(path)
+}
+
+/// Reads a sketch of the pipes, which has to contain exactly one starting tile `S` with exactly
+/// two pipes connecting to it, which lead around a closed loop.
+pub fn parse_field(input: &str) -> Result<Field, ParseError> {
+    let mut tiles = HashMap::new();
+    let mut texts = HashMap::new();
+    let mut start = None;
+
+    for (y, line) in parse::lines(input).enumerate() {
+        for (x, (i, c)) in line.char_indices().enumerate() {
+            let tile = &line[i..i + c.len_utf8()];
+            let position = (x as i64, y as i64);
+            match c {
+                '.' => continue,
+                '|' | '-' | 'L' | 'J' | '7' | 'F' => {}
+                'S' if start.is_none() => start = Some(position),
+                'S' => return Err(ParseError::new(tile, "only a single starting tile")),
+                _ => return Err(ParseError::new(tile, "a pipe, \".\" or \"S\"")),
+            }
+
+            tiles.insert(position, c);
+            texts.insert(position, tile);
+        }
+    }
+
+    let Some(start) = start else {
+        return Err(ParseError::new(
+            &input[input.len()..],
+            "a starting tile \"S\"",
+        ));
+    };
+    if connections(&tiles, start).len() != 2 {
+        return Err(ParseError::new(
+            texts[&start],
+            "a starting tile with two pipes connecting to it",
+        ));
+    }
+
+    match walk(&tiles, start) {
+        Ok(path) => Ok(Field { tiles, path }),
+        Err(broken) => Err(ParseError::new(
+            texts[&broken],
+            "a pipe that continues the loop from \"S\"",
+        )),
+    }
+}
+
+/// Every tile of the loop through `S`.
+pub fn find_loop(field: &Field) -> &HashSet<(i64, i64)> {
+    &field.path
+}
+
+/// Steps along the loop from `S` to the tile farthest away from it.
+pub fn farthest_distance(field: &Field) -> usize {
+    find_loop(field).len() / 2
+}
+
+/// Number of tiles enclosed by the loop.
+pub fn enclosed_tiles(field: &Field) -> usize {
+    let path = find_loop(field);
+
+    let max_x = path.iter().map(|(x, _)| *x).max().unwrap();
+    let max_y = path.iter().map(|(_, y)| *y).max().unwrap();
+
+    let mut inside_count = 0;
+    for y in 0..=max_y {
+        let mut inside = false;
+        for x in 0..=max_x {
+            if path.contains(&(x, y)) {
+                if !['-', 'L', 'J'].contains(&field.tiles[&(x, y)]) {
+                    inside = !inside;
+                }
+            } else if inside {
+                tracing::trace!(x, y, "inside tile");
+                inside_count += 1;
+            }
+        }
+    }
+
+    tracing::debug!(
+        inside_count,
+        loop_length = path.len(),
+        "counted inside tiles"
+    );
+
+    inside_count
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Field;
+    type Answer = usize;
+
+    const COMMANDS: &'static [Command] = queries::COMMANDS;
+
+    fn parse(input: &str) -> Result<Field, ParseError> {
+        parse_field(input)
+    }
+
+    fn part_one(field: &Field) -> usize {
+        farthest_distance(field)
+    }
+
+    fn part_two(field: &Field) -> usize {
+        enclosed_tiles(field)
+    }
+
+    fn query(field: &Field, command: &str, arguments: &[&str]) -> Result<String, String> {
+        queries::query(field, command, arguments)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parse_field;
+
+    #[test]
+    fn starting_tile_without_a_loop() {
+        let input = ".....\n.S-7.\n.|.|.\n.L-J.\n.....\n";
+        assert!(parse_field(input).is_ok());
+
+        let input = ".....\n.S-7.\n...|.\n.L-J.\n.....\n";
+        let err = parse_field(input).unwrap_err().locate(input);
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected a starting tile with two pipes connecting to it, found \"S\""
+        );
+    }
+
+    #[test]
+    fn pipes_that_do_not_close_the_loop() {
+        let error = |input| parse_field(input).unwrap_err().locate(input).to_string();
+
+        // Into the ground, into a pipe that does not connect back and off the sketch.
+        assert_eq!(
+            error(".....\n.S-7.\n.|.|.\n.L-..\n.....\n"),
+            "line 3, column 4: expected a pipe that continues the loop from \"S\", found \"|\""
+        );
+        assert_eq!(
+            error(".....\n.S-7.\n.|.|.\n.L--.\n.....\n"),
+            "line 3, column 4: expected a pipe that continues the loop from \"S\", found \"|\""
+        );
+        assert_eq!(
+            error("S-\n|.\n"),
+            "line 1, column 2: expected a pipe that continues the loop from \"S\", found \"-\""
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use common::query::{arguments, number, Command};
+
+use crate::{find_loop, Field};
+
+pub(crate) const COMMANDS: &[Command] = &[
+    Command {
+        name: "tile",
+        arguments: "<x> <y>",
+        help: "show the tile at a position and whether it is part of the loop",
+    },
+    Command {
+        name: "loop",
+        arguments: "",
+        help: "show the length of the loop",
+    },
+];
+
+pub(crate) fn query(field: &Field, command: &str, args: &[&str]) -> Result<String, String> {
+    match command {
+        "tile" => {
+            let [x, y] = arguments(args, "tile <x> <y>")?;
+            let position = (number(x)?, number(y)?);
+
+            Ok(match field.get(&position) {
+                Some(tile) if find_loop(field).contains(&position) => {
+                    format!("{tile}, part of the loop")
+                }
+                Some(tile) => format!("{tile}, not part of the loop"),
+                None => "ground".to_string(),
+            })
+        }
+        "loop" => {
+            arguments::<0>(args, "loop")?;
+            let length = find_loop(field).len();
+
+            Ok(format!(
+                "{length} tiles, the farthest {} steps away",
+                length / 2
+            ))
+        }
+        _ => unreachable!("{command} is not one of the commands"),
+    }
+}
@@ -0,0 +1,113 @@
+//! Every day of the calendar, for the tools that run them by number.
+
+use std::{
+    any::Any,
+    cell::Cell,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+use common::{Parsed, Part, Solution, Solved, Solver};
+
+/// A day of the calendar together with its solution.
+pub struct Day {
+    pub number: u8,
+    pub solver: Solver,
+}
+
+impl Day {
+    fn of<S>(number: u8) -> Day
+    where
+        S: Solution,
+        S::Input: 'static,
+    {
+        Day {
+            number,
+            solver: Solver::of::<S>(),
+        }
+    }
+
+    /// Solves `part` on input parsed by this day's solver, turning a panic of the solver into
+    /// an error message.
+    pub fn try_solve(&self, part: Part, parsed: &Parsed) -> Result<Solved, String> {
+        catch_panic(|| self.solver.solve(part, parsed))
+    }
+}
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f`, turning a panic into an error message instead of printing it.
+///
+/// Only panics on the calling thread are kept quiet; the panic hook that was installed before the
+/// first call still reports those of every other thread.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                hook(info);
+            }
+        }));
+    });
+
+    let quiet = QUIET.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    QUIET.set(quiet);
+
+    result.map_err(|payload| panic_message(payload.as_ref()))
+}
+
+/// Describes a panic by its message, if it has one.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+
+    match message {
+        Some(message) => format!("the solver panicked: {message}"),
+        None => "the solver panicked".to_string(),
+    }
+}
+
+/// Every day that can be run, queried in `aoc repl` and called from Python. `aoc new` adds new
+/// days here and to this crate's dependencies.
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::of::<day01::Day01>(1),
+        Day::of::<day02::Day02>(2),
+        Day::of::<day03::Day03>(3),
+        Day::of::<day04::Day04>(4),
+        Day::of::<day05::Day05>(5),
+        Day::of::<day06::Day06>(6),
+        Day::of::<day07::Day07>(7),
+        Day::of::<day08::Day08>(8),
+        Day::of::<day09::Day09>(9),
+        Day::of::<day10::Day10>(10),
+    ]
+}
+
+pub fn find(number: u8) -> Option<Day> {
+    all().into_iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod test {
+    use super::catch_panic;
+
+    #[test]
+    fn catching_panics() {
+        assert_eq!(catch_panic(|| 1), Ok(1));
+        assert_eq!(
+            catch_panic(|| -> u8 { panic!("no {}", "luck") }),
+            Err("the solver panicked: no luck".to_string())
+        );
+        assert_eq!(
+            catch_panic(|| catch_panic(|| -> u8 { panic!("inner") })),
+            Ok(Err("the solver panicked: inner".to_string()))
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use std::env;
+
+fn main() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let config = cbindgen::Config::from_root_or_default(&crate_dir);
+
+    // The committed `include/aoc_solvers.h` is compared against this one by `tests/header.rs`.
+    cbindgen::generate_with_config(&crate_dir, config)
+        .expect("could not generate the C header")
+        .write_to_file(format!("{out_dir}/aoc_solvers.h"));
+
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+}
@@ -0,0 +1,80 @@
+#ifndef AOC_SOLVERS_H
+#define AOC_SOLVERS_H
+
+/* Generated by cbindgen from ffi/src/lib.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The outcome of [`aoc_solve`]. The values are part of the interface and never change.
+ */
+typedef enum AocStatus {
+  AOC_STATUS_OK = 0,
+  AOC_STATUS_UNKNOWN_DAY = 1,
+  AOC_STATUS_UNKNOWN_PART = 2,
+  /**
+   * The input is not UTF-8, or a required pointer is null.
+   */
+  AOC_STATUS_INVALID_INPUT = 3,
+  AOC_STATUS_PARSE_ERROR = 4,
+  AOC_STATUS_SOLVER_PANICKED = 5,
+} AocStatus;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Solves `part` (1 or 2) of `day` on the `input_len` bytes at `input`.
+ *
+ * On success, the answer is stored in `*output`; otherwise a message describing the error is.
+ * Either string must be released with [`aoc_string_free`]. Input is normalized the same way
+ * `aoc run` does, so CRLF line endings and trailing blank lines are fine. Warnings about the
+ * input are left out, [`aoc_solve_with_warnings`] reports them as well.
+ *
+ * # Safety
+ *
+ * `input` must point to `input_len` readable bytes, or may be null if `input_len` is 0.
+ * `output` must point to writable storage for a pointer.
+ */
+enum AocStatus aoc_solve(uint8_t day,
+                         uint8_t part,
+                         const uint8_t *input,
+                         size_t input_len,
+                         char **output);
+
+/**
+ * Like [`aoc_solve`], but if `warnings` is not null, the warnings of the part are stored in
+ * `*warnings`, one per line. If there are none or solving failed, it
+ * is set to null; otherwise the string must be released with [`aoc_string_free`].
+ *
+ * # Safety
+ *
+ * As for [`aoc_solve`]. `warnings` must be null or point to writable storage for a pointer.
+ */
+enum AocStatus aoc_solve_with_warnings(uint8_t day,
+                                       uint8_t part,
+                                       const uint8_t *input,
+                                       size_t input_len,
+                                       char **output,
+                                       char **warnings);
+
+/**
+ * Releases a string returned by [`aoc_solve`] or [`aoc_solve_with_warnings`]. Null is ignored.
+ *
+ * # Safety
+ *
+ * `string` must be null or have been returned by [`aoc_solve`] or [`aoc_solve_with_warnings`],
+ * and must not be used afterwards.
+ */
+void aoc_string_free(char *string);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AOC_SOLVERS_H */
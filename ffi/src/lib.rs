@@ -0,0 +1,147 @@
+//! A C interface to every solver, for tools that are not written in Rust.
+//!
+//! The header `include/aoc_solvers.h` is generated from this file by the build script, and
+//! `tests/header.rs` checks that the committed copy is up to date.
+
+use std::{
+    ffi::{c_char, CString},
+    ptr, slice, str,
+};
+
+use common::Part;
+
+/// The outcome of [`aoc_solve`]. The values are part of the interface and never change.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    Ok = 0,
+    UnknownDay = 1,
+    UnknownPart = 2,
+    /// The input is not UTF-8, or a required pointer is null.
+    InvalidInput = 3,
+    ParseError = 4,
+    SolverPanicked = 5,
+}
+
+/// Solves `part` (1 or 2) of `day` on the `input_len` bytes at `input`.
+///
+/// On success, the answer is stored in `*output`; otherwise a message describing the error is.
+/// Either string must be released with [`aoc_string_free`]. Input is normalized the same way
+/// `aoc run` does, so CRLF line endings and trailing blank lines are fine. Warnings about the
+/// input are left out, [`aoc_solve_with_warnings`] reports them as well.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes, or may be null if `input_len` is 0.
+/// `output` must point to writable storage for a pointer.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    output: *mut *mut c_char,
+) -> AocStatus {
+    aoc_solve_with_warnings(day, part, input, input_len, output, ptr::null_mut())
+}
+
+/// Like [`aoc_solve`], but if `warnings` is not null, the warnings of the part are stored in
+/// `*warnings`, one per line. If there are none or solving failed, it
+/// is set to null; otherwise the string must be released with [`aoc_string_free`].
+///
+/// # Safety
+///
+/// As for [`aoc_solve`]. `warnings` must be null or point to writable storage for a pointer.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve_with_warnings(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    output: *mut *mut c_char,
+    warnings: *mut *mut c_char,
+) -> AocStatus {
+    if output.is_null() {
+        return AocStatus::InvalidInput;
+    }
+    if !warnings.is_null() {
+        *warnings = ptr::null_mut();
+    }
+    let input = match input {
+        _ if input_len == 0 => &[],
+        input if input.is_null() => {
+            *output = c_string("input is null".to_string());
+            return AocStatus::InvalidInput;
+        }
+        input => slice::from_raw_parts(input, input_len),
+    };
+
+    let (status, message, part_warnings) = solve(day, part, input);
+    *output = c_string(message);
+    if !warnings.is_null() && !part_warnings.is_empty() {
+        *warnings = c_string(part_warnings.join("\n"));
+    }
+    status
+}
+
+/// The status of solving `part` of `day` on `input`, with the answer or error and the warnings.
+fn solve(day: u8, part: u8, input: &[u8]) -> (AocStatus, String, Vec<String>) {
+    let Some(day) = days::find(day) else {
+        return (
+            AocStatus::UnknownDay,
+            format!("there is no day {day}"),
+            Vec::new(),
+        );
+    };
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => {
+            return (
+                AocStatus::UnknownPart,
+                format!("there is no part {part}"),
+                Vec::new(),
+            )
+        }
+    };
+    let input = match str::from_utf8(input) {
+        Ok(input) => input,
+        Err(err) => {
+            return (
+                AocStatus::InvalidInput,
+                format!("input is not UTF-8: {err}"),
+                Vec::new(),
+            )
+        }
+    };
+
+    let parsed = match days::catch_panic(|| day.solver.parse(input)) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(err)) => return (AocStatus::ParseError, err.to_string(), Vec::new()),
+        Err(message) => return (AocStatus::SolverPanicked, message, Vec::new()),
+    };
+
+    match day.try_solve(part, &parsed) {
+        Ok(solved) => (AocStatus::Ok, solved.answer, solved.warnings),
+        Err(message) => (AocStatus::SolverPanicked, message, Vec::new()),
+    }
+}
+
+fn c_string(text: String) -> *mut c_char {
+    CString::new(text.replace('\0', ""))
+        .expect("no nul bytes are left")
+        .into_raw()
+}
+
+/// Releases a string returned by [`aoc_solve`] or [`aoc_solve_with_warnings`]. Null is ignored.
+///
+/// # Safety
+///
+/// `string` must be null or have been returned by [`aoc_solve`] or [`aoc_solve_with_warnings`],
+/// and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
@@ -0,0 +1,69 @@
+/* Exercises the C interface the way a C caller would. Exits non-zero on the first mismatch. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc_solvers.h"
+
+static int expect(uint8_t day, uint8_t part, const char *input, size_t input_len,
+                  AocStatus status, const char *output) {
+    char *actual = NULL;
+    AocStatus actual_status = aoc_solve(day, part, (const uint8_t *)input, input_len, &actual);
+
+    int ok = actual_status == status && actual != NULL && strstr(actual, output) != NULL;
+    if (!ok) {
+        fprintf(stderr, "day %u part %u: expected status %d with \"%s\", got %d with \"%s\"\n",
+                day, part, status, output, actual_status, actual ? actual : "(null)");
+    }
+
+    aoc_string_free(actual);
+    return ok;
+}
+
+#define EXPECT(day, part, input, status, output) \
+    if (!expect(day, part, input, strlen(input), status, output)) return 1
+
+int main(void) {
+    const char *day01 = "1abc2\r\npqr3stu8vwx\r\na1b2c3d4e5f\r\ntreb7uchet\r\n";
+    const char *day06 = "Time:      7  15   30\nDistance:  9  40  200\n\n";
+
+    EXPECT(1, 1, day01, AOC_STATUS_OK, "142");
+    EXPECT(6, 1, day06, AOC_STATUS_OK, "288");
+    EXPECT(6, 2, day06, AOC_STATUS_OK, "71503");
+
+    EXPECT(26, 1, day06, AOC_STATUS_UNKNOWN_DAY, "there is no day 26");
+    EXPECT(6, 3, day06, AOC_STATUS_UNKNOWN_PART, "there is no part 3");
+    EXPECT(6, 1, "Time: 7\nDistance: x\n", AOC_STATUS_PARSE_ERROR, "line 2, column 11");
+    EXPECT(6, 1, "Time: \xff\n", AOC_STATUS_INVALID_INPUT, "not UTF-8");
+
+    const char *blank = "1abc2\nxyz\n";
+    char *output = NULL;
+    char *warnings = NULL;
+    AocStatus status = aoc_solve_with_warnings(1, 1, (const uint8_t *)blank, strlen(blank),
+                                               &output, &warnings);
+    if (status != AOC_STATUS_OK || strcmp(output, "12") != 0 || warnings == NULL ||
+        strstr(warnings, "line 2 has no digit") == NULL) {
+        fprintf(stderr, "expected a warning about line 2, got \"%s\"\n",
+                warnings ? warnings : "(null)");
+        return 1;
+    }
+    aoc_string_free(output);
+    aoc_string_free(warnings);
+
+    status = aoc_solve_with_warnings(1, 1, (const uint8_t *)day01, strlen(day01), &output,
+                                     &warnings);
+    if (status != AOC_STATUS_OK || warnings != NULL) {
+        fprintf(stderr, "expected no warnings, got \"%s\"\n", warnings);
+        return 1;
+    }
+    aoc_string_free(output);
+
+    if (aoc_solve(6, 1, NULL, 1, &output) != AOC_STATUS_INVALID_INPUT) {
+        fprintf(stderr, "null input was accepted\n");
+        return 1;
+    }
+    aoc_string_free(output);
+    aoc_string_free(NULL);
+
+    return 0;
+}
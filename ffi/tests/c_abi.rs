@@ -0,0 +1,37 @@
+//! Compiles `tests/c/solve.c` against the generated header and the shared library, and runs it.
+
+use std::{env, path::Path, process::Command};
+
+#[test]
+fn calling_from_c() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // The shared library is built next to this test.
+    let exe = env::current_exe().unwrap();
+    let library_dir = exe.parent().unwrap();
+    let program = library_dir.join("solve_from_c");
+
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(compiler)
+        .arg(manifest_dir.join("tests/c/solve.c"))
+        .arg("-std=c99")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(library_dir)
+        .arg(format!("-Wl,-rpath,{}", library_dir.display()))
+        .arg("-laoc_solvers")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .expect("could not run the C compiler");
+    assert!(status.success(), "compiling the C program failed");
+
+    let output = Command::new(&program).output().unwrap();
+    assert!(
+        output.status.success(),
+        "the C program failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,23 @@
+//! Checks that the committed `include/aoc_solvers.h` matches the one the build script generates.
+//!
+//! Run with `AOC_UPDATE_HEADER=1` to replace the committed header with the generated one.
+
+use std::{env, fs, path::Path};
+
+#[test]
+fn header_is_up_to_date() {
+    let generated = fs::read_to_string(Path::new(env!("OUT_DIR")).join("aoc_solvers.h")).unwrap();
+    let committed_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("include/aoc_solvers.h");
+
+    if env::var_os("AOC_UPDATE_HEADER").is_some() {
+        fs::write(&committed_path, &generated).unwrap();
+        return;
+    }
+
+    let committed = fs::read_to_string(&committed_path).unwrap_or_default();
+    assert!(
+        committed == generated,
+        "{} is out of date, run `AOC_UPDATE_HEADER=1 cargo test -p ffi --test header`",
+        committed_path.display()
+    );
+}
@@ -0,0 +1,38 @@
+"""Builds the aoc_days module with cargo and puts it next to this script.
+
+    python3 python/build.py [--debug]
+
+Afterwards `PYTHONPATH=python python3` can `import aoc_days`. Nothing but cargo and a Python
+interpreter is needed.
+"""
+
+import pathlib
+import shutil
+import subprocess
+import sys
+
+HERE = pathlib.Path(__file__).resolve().parent
+
+
+def main():
+    release = "--debug" not in sys.argv[1:]
+
+    command = ["cargo", "build", "-p", "python", "--features", "extension-module"]
+    if release:
+        command.append("--release")
+    subprocess.run(command, cwd=HERE, check=True)
+
+    target = HERE.parent / "target" / ("release" if release else "debug")
+    if sys.platform == "win32":
+        built, module = target / "aoc_days.dll", HERE / "aoc_days.pyd"
+    elif sys.platform == "darwin":
+        built, module = target / "libaoc_days.dylib", HERE / "aoc_days.so"
+    else:
+        built, module = target / "libaoc_days.so", HERE / "aoc_days.so"
+
+    shutil.copyfile(built, module)
+    print(f"built {module}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,47 @@
+use common::{Part, Solution};
+use day01::{Day01, Lexicon, Mode};
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+/// The calibration document, one line per calibration value.
+#[pyfunction]
+fn parse(input: &str) -> PyResult<String> {
+    crate::parse::<Day01>(input)
+}
+
+/// The sum, with a `UserWarning` for every line without a digit.
+#[pyfunction]
+fn part_one(py: Python<'_>, document: String) -> PyResult<usize> {
+    crate::warn(py, Part::One, &Day01::warnings(&document, Part::One))?;
+    Ok(Day01::part_one(&document))
+}
+
+/// The sum, with a `UserWarning` for every line without a digit.
+#[pyfunction]
+fn part_two(py: Python<'_>, document: String) -> PyResult<usize> {
+    crate::warn(py, Part::Two, &Day01::warnings(&document, Part::Two))?;
+    Ok(Day01::part_two(&document))
+}
+
+/// The first and last digit of `line`, read as a two-digit number, or `None` if it has no digit.
+#[pyfunction]
+fn calibration_value(line: &str) -> Option<usize> {
+    day01::calibration_value(line, Mode::Digits)
+}
+
+/// Like `calibration_value`, but digits spelled out as words count as well. `lexicon` is
+/// `"english"`, `"german"` or `"french"`.
+#[pyfunction]
+#[pyo3(signature = (line, lexicon = "english"))]
+fn spelled_calibration_value(line: &str, lexicon: &str) -> PyResult<Option<usize>> {
+    let lexicon = Lexicon::builtin(lexicon)
+        .ok_or_else(|| PyValueError::new_err(format!("there is no lexicon {lexicon:?}")))?;
+    Ok(lexicon.calibration_value(line))
+}
+
+pub fn module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(part_one, m)?)?;
+    m.add_function(wrap_pyfunction!(part_two, m)?)?;
+    m.add_function(wrap_pyfunction!(calibration_value, m)?)?;
+    m.add_function(wrap_pyfunction!(spelled_calibration_value, m)?)
+}
@@ -0,0 +1,110 @@
+use common::Solution;
+use day02::Day02;
+use pyo3::prelude::*;
+
+/// The cubes shown in one round of a game, or held by a bag.
+#[pyclass(module = "aoc_days.day02", frozen, get_all)]
+#[derive(Clone)]
+struct Cubes {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+#[pymethods]
+impl Cubes {
+    #[new]
+    fn new(red: u32, green: u32, blue: u32) -> Cubes {
+        Cubes { red, green, blue }
+    }
+
+    /// The numbers of cubes of each colour multiplied together.
+    fn power(&self) -> u32 {
+        day02::Bag::from(self).power()
+    }
+
+    fn __repr__(&self) -> String {
+        let Cubes { red, green, blue } = self;
+        format!("Cubes(red={red}, green={green}, blue={blue})")
+    }
+}
+
+impl From<&Cubes> for day02::Bag {
+    fn from(&Cubes { red, green, blue }: &Cubes) -> Self {
+        day02::Bag { red, green, blue }
+    }
+}
+
+impl From<day02::Run> for Cubes {
+    fn from(day02::Run { red, green, blue }: day02::Run) -> Self {
+        Cubes { red, green, blue }
+    }
+}
+
+impl From<day02::Bag> for Cubes {
+    fn from(day02::Bag { red, green, blue }: day02::Bag) -> Self {
+        Cubes { red, green, blue }
+    }
+}
+
+#[pyclass(module = "aoc_days.day02", frozen)]
+struct Game(day02::Game);
+
+#[pymethods]
+impl Game {
+    #[getter]
+    fn id(&self) -> u32 {
+        self.0.id
+    }
+
+    /// The cubes shown in each round.
+    #[getter]
+    fn runs(&self) -> Vec<Cubes> {
+        self.0.runs.iter().copied().map(Cubes::from).collect()
+    }
+
+    /// Whether every round could have been drawn from `bag`.
+    fn is_possible_with(&self, bag: &Cubes) -> bool {
+        self.0.is_possible_with(&bag.into())
+    }
+
+    /// The fewest cubes of each colour that make the game possible.
+    fn minimal_bag(&self) -> Cubes {
+        self.0.minimal_bag().into()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Game(id={}, rounds={})", self.0.id, self.0.runs.len())
+    }
+}
+
+fn games(games: Vec<PyRef<Game>>) -> Vec<day02::Game> {
+    games.iter().map(|game| game.0.clone()).collect()
+}
+
+#[pyfunction]
+fn parse(input: &str) -> PyResult<Vec<Game>> {
+    Ok(crate::parse::<Day02>(input)?
+        .into_iter()
+        .map(Game)
+        .collect())
+}
+
+#[pyfunction]
+fn part_one(games: Vec<PyRef<Game>>) -> u32 {
+    Day02::part_one(&self::games(games))
+}
+
+#[pyfunction]
+fn part_two(games: Vec<PyRef<Game>>) -> u32 {
+    Day02::part_two(&self::games(games))
+}
+
+pub fn module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Cubes>()?;
+    m.add_class::<Game>()?;
+    m.add("BAG", Cubes::from(day02::BAG))?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(part_one, m)?)?;
+    m.add_function(wrap_pyfunction!(part_two, m)?)
+}
@@ -0,0 +1,55 @@
+use common::Solution;
+use day03::Day03;
+use pyo3::prelude::*;
+
+/// An engine schematic, reduced to its symbols and part numbers.
+#[pyclass(module = "aoc_days.day03", frozen)]
+struct Schematic(day03::Schematic);
+
+#[pymethods]
+impl Schematic {
+    /// Every number that is adjacent to at least one symbol.
+    #[getter]
+    fn part_numbers(&self) -> Vec<u32> {
+        self.0.part_numbers().to_vec()
+    }
+
+    /// Every symbol as `((x, y), symbol, adjacent_numbers)`.
+    #[getter]
+    fn symbols(&self) -> Vec<((usize, usize), char, Vec<u32>)> {
+        let mut symbols: Vec<_> = self
+            .0
+            .symbols()
+            .map(|(position, c, numbers)| (position, c, numbers.to_vec()))
+            .collect();
+        symbols.sort_unstable_by_key(|&((x, y), _, _)| (y, x));
+        symbols
+    }
+
+    /// The product of the two numbers of every `*` that is adjacent to exactly two numbers.
+    fn gear_ratios(&self) -> Vec<u32> {
+        self.0.gear_ratios().collect()
+    }
+}
+
+#[pyfunction]
+fn parse(input: &str) -> PyResult<Schematic> {
+    crate::parse::<Day03>(input).map(Schematic)
+}
+
+#[pyfunction]
+fn part_one(schematic: &Schematic) -> u32 {
+    Day03::part_one(&schematic.0)
+}
+
+#[pyfunction]
+fn part_two(schematic: &Schematic) -> u32 {
+    Day03::part_two(&schematic.0)
+}
+
+pub fn module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Schematic>()?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(part_one, m)?)?;
+    m.add_function(wrap_pyfunction!(part_two, m)?)
+}
@@ -0,0 +1,72 @@
+use std::collections::HashSet;
+
+use common::Solution;
+use day04::Day04;
+use pyo3::prelude::*;
+
+/// A scratchcard.
+#[pyclass(module = "aoc_days.day04", frozen)]
+struct Card(day04::Card);
+
+#[pymethods]
+impl Card {
+    #[getter]
+    fn id(&self) -> u32 {
+        self.0.id()
+    }
+
+    /// The numbers left of the `|`.
+    #[getter]
+    fn winning_numbers(&self) -> HashSet<u32> {
+        self.0.winning_numbers().clone()
+    }
+
+    /// The numbers right of the `|`.
+    #[getter]
+    fn numbers(&self) -> HashSet<u32> {
+        self.0.numbers().clone()
+    }
+
+    /// How many of the numbers are winning numbers.
+    fn matches(&self) -> usize {
+        self.0.matches()
+    }
+
+    /// The points of part one.
+    fn worth(&self) -> u32 {
+        self.0.worth()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Card(id={})", self.0.id())
+    }
+}
+
+fn cards(cards: Vec<PyRef<Card>>) -> Vec<day04::Card> {
+    cards.iter().map(|card| card.0.clone()).collect()
+}
+
+#[pyfunction]
+fn parse(input: &str) -> PyResult<Vec<Card>> {
+    Ok(crate::parse::<Day04>(input)?
+        .into_iter()
+        .map(Card)
+        .collect())
+}
+
+#[pyfunction]
+fn part_one(cards: Vec<PyRef<Card>>) -> u32 {
+    Day04::part_one(&self::cards(cards))
+}
+
+#[pyfunction]
+fn part_two(cards: Vec<PyRef<Card>>) -> u32 {
+    Day04::part_two(&self::cards(cards))
+}
+
+pub fn module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Card>()?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(part_one, m)?)?;
+    m.add_function(wrap_pyfunction!(part_two, m)?)
+}
@@ -0,0 +1,130 @@
+use common::Solution;
+use day05::{Day05, Entry, Kind};
+use pyo3::prelude::*;
+
+/// A line of a map: numbers in `range(start, end)` are moved by `offset`.
+#[pyclass(module = "aoc_days.day05", frozen, get_all)]
+#[derive(Clone)]
+struct Rule {
+    start: i64,
+    end: i64,
+    offset: i64,
+}
+
+#[pymethods]
+impl Rule {
+    fn __repr__(&self) -> String {
+        let Rule { start, end, offset } = self;
+        format!("Rule(start={start}, end={end}, offset={offset})")
+    }
+}
+
+/// A map like `seed-to-soil map:` with its rules.
+#[pyclass(module = "aoc_days.day05", frozen, get_all)]
+#[derive(Clone)]
+struct Map {
+    source: String,
+    destination: String,
+    rules: Vec<Rule>,
+}
+
+#[pymethods]
+impl Map {
+    fn __repr__(&self) -> String {
+        format!(
+            "Map(source={:?}, destination={:?}, rules={})",
+            self.source,
+            self.destination,
+            self.rules.len()
+        )
+    }
+}
+
+/// The name of `kind` as the almanac writes it.
+fn category(kind: Kind) -> String {
+    format!("{kind:?}").to_lowercase()
+}
+
+fn ranges(entry: Entry) -> Vec<(i64, i64)> {
+    entry
+        .ranges
+        .into_iter()
+        .map(|range| (range.start, range.end))
+        .collect()
+}
+
+/// The seeds to plant and the maps that lead from seeds to locations.
+#[pyclass(module = "aoc_days.day05", frozen)]
+struct Almanac(day05::Almanac);
+
+#[pymethods]
+impl Almanac {
+    #[getter]
+    fn seeds(&self) -> Vec<i64> {
+        self.0.seeds().to_vec()
+    }
+
+    #[getter]
+    fn maps(&self) -> Vec<Map> {
+        self.0
+            .maps()
+            .iter()
+            .map(|map| Map {
+                source: category(map.from()),
+                destination: category(map.to()),
+                rules: map
+                    .rules()
+                    .iter()
+                    .map(|rule| Rule {
+                        start: rule.source_range().start,
+                        end: rule.source_range().end,
+                        offset: rule.offset(),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Every seed number as a range `(start, end)` of its own.
+    fn single_seeds(&self) -> Vec<(i64, i64)> {
+        ranges(self.0.single_seeds())
+    }
+
+    /// The seed numbers read as pairs of range start and length, as ranges `(start, end)`.
+    fn seed_ranges(&self) -> Vec<(i64, i64)> {
+        ranges(self.0.seed_ranges())
+    }
+
+    /// The lowest location any of the seed ranges `(start, end)` ends up at, or `None` if the
+    /// ranges are empty.
+    fn nearest_location(&self, seeds: Vec<(i64, i64)>) -> Option<i64> {
+        self.0.nearest_location(Entry {
+            kind: Kind::Seed,
+            ranges: seeds.into_iter().map(|(start, end)| start..end).collect(),
+        })
+    }
+}
+
+#[pyfunction]
+fn parse(input: &str) -> PyResult<Almanac> {
+    crate::parse::<Day05>(input).map(Almanac)
+}
+
+#[pyfunction]
+fn part_one(almanac: &Almanac) -> i64 {
+    Day05::part_one(&almanac.0)
+}
+
+#[pyfunction]
+fn part_two(almanac: &Almanac) -> i64 {
+    Day05::part_two(&almanac.0)
+}
+
+pub fn module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Rule>()?;
+    m.add_class::<Map>()?;
+    m.add_class::<Almanac>()?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(part_one, m)?)?;
+    m.add_function(wrap_pyfunction!(part_two, m)?)
+}
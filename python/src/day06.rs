@@ -0,0 +1,79 @@
+use common::Solution;
+use day06::Day06;
+use pyo3::prelude::*;
+
+/// A boat race: how long it lasts and the farthest distance travelled in it so far.
+#[pyclass(module = "aoc_days.day06", frozen)]
+struct Race(day06::Race);
+
+#[pymethods]
+impl Race {
+    #[new]
+    fn new(time: i64, record: u64) -> Race {
+        Race(day06::Race { time, record })
+    }
+
+    #[getter]
+    fn time(&self) -> i64 {
+        self.0.time
+    }
+
+    #[getter]
+    fn record(&self) -> u64 {
+        self.0.record
+    }
+
+    /// In how many ways the button can be held to beat the record.
+    fn margin_of_error(&self) -> u64 {
+        self.0.margin_of_error()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Race(time={}, record={})", self.0.time, self.0.record)
+    }
+}
+
+/// The races of a sheet, in order.
+#[pyclass(module = "aoc_days.day06", frozen)]
+struct RaceData(day06::RaceData);
+
+#[pymethods]
+impl RaceData {
+    #[getter]
+    fn races(&self) -> Vec<Race> {
+        self.0.races().iter().copied().map(Race).collect()
+    }
+
+    /// The numbers of all races read as a single race, ignoring the spaces between them.
+    fn joined(&self) -> RaceData {
+        RaceData(self.0.joined())
+    }
+
+    /// The margins of error of all races multiplied together.
+    fn product_of_error_margin(&self) -> u64 {
+        self.0.product_of_error_margin()
+    }
+}
+
+#[pyfunction]
+fn parse(input: &str) -> PyResult<RaceData> {
+    crate::parse::<Day06>(input).map(RaceData)
+}
+
+#[pyfunction]
+fn part_one(races: &RaceData) -> u64 {
+    Day06::part_one(&races.0)
+}
+
+#[pyfunction]
+fn part_two(races: &RaceData) -> u64 {
+    Day06::part_two(&races.0)
+}
+
+pub fn module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Race>()?;
+    m.add_class::<RaceData>()?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(part_one, m)?)?;
+    m.add_function(wrap_pyfunction!(part_two, m)?)
+}
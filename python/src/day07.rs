@@ -0,0 +1,107 @@
+use common::Solution;
+use day07::Day07;
+use pyo3::prelude::*;
+
+/// The kind of a hand, ordered by strength.
+#[pyclass(module = "aoc_days.day07", frozen, eq, eq_int, ord)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum HandKind {
+    HighCard,
+    Pair,
+    TwoPairs,
+    ThreeOf,
+    FullHouse,
+    FourOf,
+    FiveOf,
+}
+
+impl From<day07::HandKind> for HandKind {
+    fn from(kind: day07::HandKind) -> Self {
+        match kind {
+            day07::HandKind::HighCard => HandKind::HighCard,
+            day07::HandKind::Pair => HandKind::Pair,
+            day07::HandKind::TwoPairs => HandKind::TwoPairs,
+            day07::HandKind::ThreeOf => HandKind::ThreeOf,
+            day07::HandKind::FullHouse => HandKind::FullHouse,
+            day07::HandKind::FourOf => HandKind::FourOf,
+            day07::HandKind::FiveOf => HandKind::FiveOf,
+        }
+    }
+}
+
+/// Five cards of Camel Cards.
+#[pyclass(module = "aoc_days.day07", frozen)]
+struct Hand(day07::Hand);
+
+#[pymethods]
+impl Hand {
+    /// The cards as written in the input, like `"32T3K"`.
+    #[getter]
+    fn cards(&self) -> String {
+        self.0.cards().map(|card| format!("{card:?}")).concat()
+    }
+
+    fn kind(&self) -> HandKind {
+        self.0.kind().into()
+    }
+
+    /// The same hand with every Jack read as a Joker.
+    fn with_jokers(&self) -> Hand {
+        Hand(self.0.with_jokers())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Hand({:?})", self.cards())
+    }
+}
+
+/// Every hand of a game of Camel Cards together with its bid.
+#[pyclass(module = "aoc_days.day07", frozen)]
+struct Game(day07::Game);
+
+#[pymethods]
+impl Game {
+    /// Every hand as `(hand, bid)`.
+    #[getter]
+    fn hands(&self) -> Vec<(Hand, u32)> {
+        self.0
+            .hands()
+            .iter()
+            .map(|(hand, bid)| (Hand(hand.clone()), *bid))
+            .collect()
+    }
+
+    /// The same hands with every Jack acting as a Joker.
+    fn with_jokers(&self) -> Game {
+        Game(self.0.with_jokers())
+    }
+
+    /// The sum of every bid multiplied by the rank of its hand.
+    fn total_winnings(&self) -> u32 {
+        self.0.total_winnings()
+    }
+}
+
+#[pyfunction]
+fn parse(input: &str) -> PyResult<Game> {
+    crate::parse::<Day07>(input).map(Game)
+}
+
+#[pyfunction]
+fn part_one(game: &Game) -> u32 {
+    Day07::part_one(&game.0)
+}
+
+#[pyfunction]
+fn part_two(game: &Game) -> u32 {
+    Day07::part_two(&game.0)
+}
+
+pub fn module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<HandKind>()?;
+    m.add_class::<Hand>()?;
+    m.add_class::<Game>()?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(part_one, m)?)?;
+    m.add_function(wrap_pyfunction!(part_two, m)?)
+}
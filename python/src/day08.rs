@@ -0,0 +1,71 @@
+use common::Solution;
+use day08::{Day08, Direction};
+use pyo3::prelude::*;
+
+/// The directions to follow and the network of nodes.
+#[pyclass(module = "aoc_days.day08", frozen)]
+struct Network(day08::Input);
+
+#[pymethods]
+impl Network {
+    /// The directions as written in the input, like `"LLR"`.
+    #[getter]
+    fn directions(&self) -> String {
+        self.0
+            .directions()
+            .iter()
+            .map(|direction| match direction {
+                Direction::Left => 'L',
+                Direction::Right => 'R',
+            })
+            .collect()
+    }
+
+    /// Every node as `(name, left, right)`, sorted by name.
+    #[getter]
+    fn nodes(&self) -> Vec<(&str, &str, &str)> {
+        self.0
+            .network()
+            .iter()
+            .map(|node| (node.name(), node.left(), node.right()))
+            .collect()
+    }
+
+    /// Where the node `name` leads as `(left, right)`, if there is such a node.
+    fn node(&self, name: &str) -> Option<(&str, &str)> {
+        self.0.node(name).map(|node| (node.left(), node.right()))
+    }
+
+    /// Steps needed to walk from `AAA` to `ZZZ`.
+    fn steps_to_zzz(&self) -> Option<usize> {
+        self.0.steps_to_zzz()
+    }
+
+    /// Steps needed until walking from every node ending in `A` at once ends up only at nodes
+    /// ending in `Z`, or `None` if the network does not have the shape this relies on.
+    fn required_steps(&self) -> Option<usize> {
+        self.0.required_steps()
+    }
+}
+
+#[pyfunction]
+fn parse(input: &str) -> PyResult<Network> {
+    crate::parse::<Day08>(input).map(Network)
+}
+
+#[pyfunction]
+fn part_one(network: &Network) -> usize {
+    Day08::part_one(&network.0)
+}
+
+#[pyfunction]
+fn part_two(network: &Network) -> usize {
+    Day08::part_two(&network.0)
+}
+
+pub fn module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Network>()?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(part_one, m)?)?;
+    m.add_function(wrap_pyfunction!(part_two, m)?)
+}
@@ -0,0 +1,47 @@
+use common::Solution;
+use day09::Day09;
+use pyo3::prelude::*;
+
+/// The OASIS report: the history of every value.
+#[pyclass(module = "aoc_days.day09", frozen)]
+struct Report(day09::Report);
+
+#[pymethods]
+impl Report {
+    #[getter]
+    fn histories(&self) -> Vec<Vec<i64>> {
+        self.0.histories().to_vec()
+    }
+
+    /// Sum of the next value of every history.
+    fn forward_sum(&self) -> i64 {
+        self.0.forward_sum()
+    }
+
+    /// Sum of the value before the first one of every history.
+    fn extrapolated_sum(&self) -> i64 {
+        self.0.extrapolated_sum()
+    }
+}
+
+#[pyfunction]
+fn parse(input: &str) -> PyResult<Report> {
+    crate::parse::<Day09>(input).map(Report)
+}
+
+#[pyfunction]
+fn part_one(report: &Report) -> i64 {
+    Day09::part_one(&report.0)
+}
+
+#[pyfunction]
+fn part_two(report: &Report) -> i64 {
+    Day09::part_two(&report.0)
+}
+
+pub fn module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Report>()?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(part_one, m)?)?;
+    m.add_function(wrap_pyfunction!(part_two, m)?)
+}
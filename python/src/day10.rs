@@ -0,0 +1,55 @@
+use std::collections::{HashMap, HashSet};
+
+use common::Solution;
+use day10::Day10;
+use pyo3::prelude::*;
+
+/// A sketch of the pipes.
+#[pyclass(module = "aoc_days.day10", frozen)]
+struct Field(day10::Field);
+
+#[pymethods]
+impl Field {
+    /// Every tile that is not ground, by its position `(x, y)`.
+    #[getter]
+    fn tiles(&self) -> HashMap<(i64, i64), char> {
+        self.0.tiles().clone()
+    }
+
+    /// The position of every tile of the loop through `S`.
+    fn find_loop(&self) -> HashSet<(i64, i64)> {
+        day10::find_loop(&self.0).clone()
+    }
+
+    /// Steps along the loop from `S` to the tile farthest away from it.
+    fn farthest_distance(&self) -> usize {
+        day10::farthest_distance(&self.0)
+    }
+
+    /// Number of tiles enclosed by the loop.
+    fn enclosed_tiles(&self) -> usize {
+        day10::enclosed_tiles(&self.0)
+    }
+}
+
+#[pyfunction]
+fn parse(input: &str) -> PyResult<Field> {
+    crate::parse::<Day10>(input).map(Field)
+}
+
+#[pyfunction]
+fn part_one(field: &Field) -> usize {
+    Day10::part_one(&field.0)
+}
+
+#[pyfunction]
+fn part_two(field: &Field) -> usize {
+    Day10::part_two(&field.0)
+}
+
+pub fn module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Field>()?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(part_one, m)?)?;
+    m.add_function(wrap_pyfunction!(part_two, m)?)
+}
@@ -0,0 +1,70 @@
+use common::{Parsed, Part};
+use days::Day;
+use pyo3::{
+    exceptions::{PyRuntimeError, PyValueError},
+    prelude::*,
+};
+
+/// The parsed input of any day, opaque to Python.
+#[pyclass(module = "aoc_days", unsendable)]
+pub struct Model {
+    day: Day,
+    parsed: Parsed,
+}
+
+#[pymethods]
+impl Model {
+    fn __repr__(&self) -> String {
+        format!("Model(day={})", self.day.number)
+    }
+}
+
+impl Model {
+    fn solve(&self, py: Python<'_>, part: Part) -> PyResult<String> {
+        let solved = self
+            .day
+            .try_solve(part, &self.parsed)
+            .map_err(PyRuntimeError::new_err)?;
+        crate::answer(py, part, solved)
+    }
+}
+
+/// Parses `input` for `day` into a model that `part_one` and `part_two` solve.
+#[pyfunction]
+pub fn parse(day: u8, input: &str) -> PyResult<Model> {
+    let Some(day) = days::find(day) else {
+        return Err(PyValueError::new_err(format!("there is no day {day}")));
+    };
+    let parsed = day
+        .solver
+        .parse(input)
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+    Ok(Model { day, parsed })
+}
+
+/// Solves part one of a model and returns the answer as a string.
+#[pyfunction]
+pub fn part_one(py: Python<'_>, model: &Model) -> PyResult<String> {
+    model.solve(py, Part::One)
+}
+
+/// Solves part two of a model and returns the answer as a string.
+#[pyfunction]
+pub fn part_two(py: Python<'_>, model: &Model) -> PyResult<String> {
+    model.solve(py, Part::Two)
+}
+
+/// Fills the submodule of a day without bindings of its own, where `parse(input)` returns a
+/// [`Model`].
+pub fn module(m: &Bound<'_, PyModule>, day: u8) -> PyResult<()> {
+    let parse = m
+        .py()
+        .import("functools")?
+        .getattr("partial")?
+        .call1((wrap_pyfunction!(parse, m)?, day))?;
+
+    m.add("parse", parse)?;
+    m.add_function(wrap_pyfunction!(part_one, m)?)?;
+    m.add_function(wrap_pyfunction!(part_two, m)?)
+}
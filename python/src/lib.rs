@@ -0,0 +1,136 @@
+//! A Python module `aoc_days` with the parsed model and the solutions of every day.
+//!
+//! Every day has a submodule like `aoc_days.day05` with `parse(input)`, `part_one(model)` and
+//! `part_two(model)`. Days with bindings of their own add classes for what `parse` returns; the
+//! others return the opaque model of `aoc_days.parse(day, input)`. `aoc_days.solve(day, part,
+//! input)` runs a day the way `aoc run` does.
+
+use std::ffi::CString;
+
+use common::{Part, Solution, Solved};
+use pyo3::{
+    exceptions::{PyRuntimeError, PyUserWarning, PyValueError},
+    prelude::*,
+};
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod generic;
+
+/// Parses `input` the way `aoc` does: normalized first, with errors located within it.
+fn parse<S: Solution>(input: &str) -> PyResult<S::Input> {
+    common::parse_input::<S>(input).map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
+/// Issues a `UserWarning` for every warning of `part`, the way `aoc run` prints them to stderr.
+fn warn(py: Python<'_>, part: Part, warnings: &[String]) -> PyResult<()> {
+    for warning in warnings {
+        let message = CString::new(format!("part {part}: {warning}").replace('\0', ""))
+            .expect("no nul bytes are left");
+        PyErr::warn(py, &py.get_type::<PyUserWarning>(), &message, 1)?;
+    }
+    Ok(())
+}
+
+/// The answer to `part` after [warning](warn) about the rest.
+fn answer(py: Python<'_>, part: Part, solved: Solved) -> PyResult<String> {
+    warn(py, part, &solved.warnings)?;
+    Ok(solved.answer)
+}
+
+/// Solves `part` (1 or 2) of `day` on `input` and returns the answer as a string.
+#[pyfunction]
+fn solve(py: Python<'_>, day: u8, part: u8, input: &str) -> PyResult<String> {
+    let Some(day) = days::find(day) else {
+        return Err(PyValueError::new_err(format!("there is no day {day}")));
+    };
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return Err(PyValueError::new_err(format!("there is no part {part}"))),
+    };
+
+    let parsed = day
+        .solver
+        .parse(input)
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let solved = day
+        .try_solve(part, &parsed)
+        .map_err(PyRuntimeError::new_err)?;
+    answer(py, part, solved)
+}
+
+/// The numbers of every day that can be solved.
+#[pyfunction]
+fn available_days() -> Vec<u32> {
+    // A `Vec<u8>` would turn into `bytes`.
+    days::all().iter().map(|day| day.number.into()).collect()
+}
+
+/// Fills the submodule of a day.
+type Bindings = fn(&Bound<'_, PyModule>) -> PyResult<()>;
+
+/// The bindings written for `day`, with classes for what its `parse` returns.
+fn bindings(day: u8) -> Option<Bindings> {
+    let module: Bindings = match day {
+        1 => day01::module,
+        2 => day02::module,
+        3 => day03::module,
+        4 => day04::module,
+        5 => day05::module,
+        6 => day06::module,
+        7 => day07::module,
+        8 => day08::module,
+        9 => day09::module,
+        10 => day10::module,
+        _ => return None,
+    };
+
+    Some(module)
+}
+
+/// Adds the submodule `name` to `parent`, also under its full name so that
+/// `import aoc_days.day05` works.
+fn add_submodule<'py>(
+    parent: &Bound<'py, PyModule>,
+    name: &str,
+    fill: impl FnOnce(&Bound<'py, PyModule>) -> PyResult<()>,
+) -> PyResult<()> {
+    let py = parent.py();
+    let module = PyModule::new(py, name)?;
+    fill(&module)?;
+
+    parent.add_submodule(&module)?;
+    py.import("sys")?
+        .getattr("modules")?
+        .set_item(format!("aoc_days.{name}"), &module)
+}
+
+#[pymodule]
+fn aoc_days(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(available_days, m)?)?;
+
+    m.add_class::<generic::Model>()?;
+    m.add_function(wrap_pyfunction!(generic::parse, m)?)?;
+    m.add_function(wrap_pyfunction!(generic::part_one, m)?)?;
+    m.add_function(wrap_pyfunction!(generic::part_two, m)?)?;
+
+    for day in days::all() {
+        let name = format!("day{:02}", day.number);
+        add_submodule(m, &name, |module| match bindings(day.number) {
+            Some(bindings) => bindings(module),
+            None => generic::module(module, day.number),
+        })?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+//! Runs `tests/test_aoc_days.py` against the module built for this test.
+//!
+//! The library is also an `rlib` only so that cargo builds it before this test.
+
+use std::{
+    env::{
+        self,
+        consts::{DLL_PREFIX, DLL_SUFFIX},
+    },
+    fs,
+    path::Path,
+    process::Command,
+};
+
+#[test]
+fn calling_from_python() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // The library is built next to this test, and Python wants it named after the module.
+    let exe = env::current_exe().unwrap();
+    let library = exe
+        .parent()
+        .unwrap()
+        .join(format!("{DLL_PREFIX}aoc_days{DLL_SUFFIX}"));
+    let module = if cfg!(windows) {
+        "aoc_days.pyd"
+    } else {
+        "aoc_days.so"
+    };
+    let module_dir = env::temp_dir().join(format!("aoc-python-{}", std::process::id()));
+    fs::create_dir_all(&module_dir).unwrap();
+    fs::copy(&library, module_dir.join(module)).unwrap();
+
+    let python = env::var("PYO3_PYTHON").unwrap_or_else(|_| "python3".to_string());
+    let output = Command::new(python)
+        .args(["-m", "unittest", "discover", "-s"])
+        .arg(manifest_dir.join("tests"))
+        .env("PYTHONPATH", &module_dir)
+        .output()
+        .expect("could not run Python");
+
+    fs::remove_dir_all(&module_dir).unwrap();
+    assert!(
+        output.status.success(),
+        "the Python tests failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,123 @@
+"""Tests of the aoc_days module against the examples shipped with every day.
+
+Run them with the module on the path, e.g. after `python3 python/build.py`:
+
+    PYTHONPATH=python python3 -m unittest discover -s python/tests
+"""
+
+import pathlib
+import unittest
+
+import aoc_days
+import aoc_days.day05
+from aoc_days import day01, day05, day06, day07, day09, day10
+
+ROOT = pathlib.Path(__file__).resolve().parents[2]
+
+
+def example(day, name="test_input"):
+    return (ROOT / f"day{day:02}" / "src" / name).read_text()
+
+
+def examples():
+    """Every example with its recorded answers, as `(day, input, {part: answer})`."""
+    for answers in sorted(ROOT.glob("day*/src/test_input*.answers")):
+        day = int(answers.parts[-3][3:])
+        recorded = {}
+        for line in answers.read_text().splitlines():
+            if line.strip():
+                part, answer = line.removeprefix("part ").split(":")
+                recorded[int(part)] = answer.strip()
+        yield day, answers.with_suffix("").read_text(), recorded
+
+
+class Examples(unittest.TestCase):
+    def test_parts(self):
+        for day, text, answers in examples():
+            module = getattr(aoc_days, f"day{day:02}")
+            model = module.parse(text)
+            functions = {1: module.part_one, 2: module.part_two}
+            for part, answer in answers.items():
+                with self.subTest(day=day, part=part):
+                    self.assertEqual(str(functions[part](model)), answer)
+                    self.assertEqual(aoc_days.solve(day, part, text), answer)
+
+    def test_models_of_any_day(self):
+        model = aoc_days.parse(7, example(7))
+        self.assertEqual(repr(model), "Model(day=7)")
+        self.assertEqual(aoc_days.part_one(model), "6440")
+        self.assertEqual(aoc_days.part_two(model), "5905")
+
+    def test_available_days(self):
+        self.assertEqual(aoc_days.available_days(), list(range(1, 11)))
+
+
+class Models(unittest.TestCase):
+    def test_calibration_values(self):
+        self.assertEqual(day01.calibration_value("a1b2c3"), 13)
+        self.assertEqual(day01.spelled_calibration_value("zero5oneight"), 8)
+        self.assertEqual(day01.spelled_calibration_value("dreiundvier", "german"), 34)
+        self.assertIsNone(day01.calibration_value("eightwothree"))
+        with self.assertRaisesRegex(ValueError, "no lexicon"):
+            day01.spelled_calibration_value("one", "klingon")
+        with self.assertWarnsRegex(UserWarning, "part 1: line 2 has no digit"):
+            self.assertEqual(day01.part_one(day01.parse(example(1, "test_input2"))), 209)
+
+    def test_almanac(self):
+        almanac = aoc_days.day05.parse(example(5))
+        self.assertEqual(almanac.seeds, [79, 14, 55, 13])
+
+        seed_to_soil = almanac.maps[0]
+        self.assertEqual((seed_to_soil.source, seed_to_soil.destination), ("seed", "soil"))
+        self.assertEqual(
+            [(rule.start, rule.end, rule.offset) for rule in seed_to_soil.rules],
+            [(98, 100, -48), (50, 98, 2)],
+        )
+        self.assertEqual(almanac.maps[-1].destination, "location")
+
+        self.assertEqual(almanac.seed_ranges(), [(79, 93), (55, 68)])
+        self.assertEqual(almanac.nearest_location([(79, 80)]), 82)
+
+    def test_races(self):
+        races = day06.parse(example(6))
+        self.assertEqual([race.margin_of_error() for race in races.races], [4, 8, 9])
+        self.assertEqual(day06.Race(71530, 940200).margin_of_error(), 71503)
+
+    def test_hand_kinds(self):
+        game = day07.parse(example(7))
+        hands = {hand.cards: hand for hand, _ in game.hands}
+
+        self.assertEqual(hands["32T3K"].kind(), day07.HandKind.Pair)
+        self.assertEqual(hands["KTJJT"].kind(), day07.HandKind.TwoPairs)
+        self.assertEqual(hands["KTJJT"].with_jokers().kind(), day07.HandKind.FourOf)
+        self.assertLess(day07.HandKind.Pair, day07.HandKind.FourOf)
+
+    def test_histories(self):
+        report = day09.parse(example(9))
+        self.assertEqual(report.histories[0], [0, 3, 6, 9, 12, 15])
+        self.assertEqual(report.forward_sum(), 114)
+
+    def test_loop(self):
+        field = day10.parse(example(10))
+        path = field.find_loop()
+
+        self.assertEqual(len(path), 16)
+        self.assertIn((0, 2), path)
+        self.assertEqual(field.tiles[(0, 2)], "S")
+        self.assertEqual(field.farthest_distance(), 8)
+
+
+class Errors(unittest.TestCase):
+    def test_parse_errors(self):
+        with self.assertRaisesRegex(ValueError, "line 2, column 11"):
+            day06.parse("Time: 7\nDistance: x\n")
+
+    def test_unknown_days_and_parts(self):
+        with self.assertRaisesRegex(ValueError, "no day 26"):
+            aoc_days.solve(26, 1, "")
+        with self.assertRaisesRegex(ValueError, "no part 3"):
+            aoc_days.solve(6, 3, example(6))
+
+
+if __name__ == "__main__":
+    unittest.main()